@@ -159,6 +159,106 @@ where <Self as Radium>::Item: BitRegister
 		}
 	}
 
+	/// Sets any number of bits in a memory register to `1`, with a chosen
+	/// memory ordering.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `mask`: A selection mask of the bits to set.
+	/// - `order`: The memory ordering of the read-modify-write operation.
+	///
+	/// # Returns
+	///
+	/// The value of the register before it was modified.
+	fn fetch_set_bits(
+		&self,
+		mask: BitMask<Self::Item>,
+		order: atomic::Ordering,
+	) -> Self::Item
+	{
+		self.fetch_or(mask.value(), order)
+	}
+
+	/// Clears any number of bits in a memory register to `0`, with a chosen
+	/// memory ordering.
+	///
+	/// See [`fetch_set_bits`] for the parameters and return value.
+	///
+	/// [`fetch_set_bits`]: Self::fetch_set_bits
+	fn fetch_clear_bits(
+		&self,
+		mask: BitMask<Self::Item>,
+		order: atomic::Ordering,
+	) -> Self::Item
+	{
+		self.fetch_and(!mask.value(), order)
+	}
+
+	/// Inverts any number of bits in a memory register, with a chosen memory
+	/// ordering.
+	///
+	/// See [`fetch_set_bits`] for the parameters and return value.
+	///
+	/// [`fetch_set_bits`]: Self::fetch_set_bits
+	fn fetch_invert_bits(
+		&self,
+		mask: BitMask<Self::Item>,
+		order: atomic::Ordering,
+	) -> Self::Item
+	{
+		self.fetch_xor(mask.value(), order)
+	}
+
+	/// Writes `new` into one bit of a memory register, if that bit is
+	/// currently `current`.
+	///
+	/// Changes to other bits in the register do not cause this to fail.
+	///
+	/// # Type Parameters
+	///
+	/// - `O`: A bit ordering.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The semantic index of the bit in `*self` to exchange.
+	/// - `current`: The value the bit must have for the exchange to occur.
+	/// - `new`: The value to write into the bit.
+	/// - `success`: The memory ordering of the read-modify-write operation if
+	///   the exchange occurs.
+	/// - `failure`: The memory ordering of the load if it does not.
+	///
+	/// # Returns
+	///
+	/// `Ok` of the previous bit if it was `current` and has been replaced, or
+	/// `Err` of the observed bit if it was not.
+	fn compare_exchange_bit<O>(
+		&self,
+		index: BitIdx<Self::Item>,
+		current: bool,
+		new: bool,
+		success: atomic::Ordering,
+		failure: atomic::Ordering,
+	) -> Result<bool, bool>
+	where
+		O: BitOrder,
+	{
+		let sel = index.select::<O>().value();
+		let mut old = self.load(failure);
+		loop {
+			let bit = old & sel == sel;
+			if bit != current {
+				return Err(bit);
+			}
+			let next = if new { old | sel } else { old & !sel };
+			match self.compare_exchange_weak(old, next, success, failure) {
+				Ok(_) => return Ok(bit),
+				Err(actual) => old = actual,
+			}
+		}
+	}
+
 	/// Gets the function that writes `value` into all bits under a mask.
 	///
 	/// # Parameters
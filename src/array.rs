@@ -80,7 +80,8 @@ code.
 
 This does not track start or end indices of its [`BitSlice`] view, and so that
 view will always fully span the buffer. You cannot produce, for example, an
-array of twelve bits.
+array of twelve bits. The [`BitArrayExact`] type, produced by the [`BitArr!`]
+macro, carries its bit-count in its type and only exposes that many bits.
 
 # Type Parameters
 
@@ -136,6 +137,8 @@ When const-generics stabilize, this will be modified to have a signature more
 like `BitArray<O, T, const N: usize>([T; elts::<T>(N)]);`, to mirror the
 behavior of ordinary arrays `[T; N]` as they stand today.
 
+[`BitArr!`]: macro@crate::BitArr
+[`BitArrayExact`]: self::BitArrayExact
 [`BitSlice`]: crate::slice::BitSlice
 [`BitView`]: crate::view::BitView
 [`Deref`]: core::ops::Deref
@@ -248,11 +251,15 @@ where
 	}
 }
 
+mod exact;
 mod iter;
 mod ops;
 mod traits;
 
-pub use self::iter::IntoIter;
+pub use self::{
+	exact::BitArrayExact,
+	iter::IntoIter,
+};
 
 #[cfg(test)]
 mod tests;
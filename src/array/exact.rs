@@ -0,0 +1,449 @@
+/*! A [`BitArray`] trimmed to an exact number of bits.
+
+[`BitArray`] always spans its entire buffer, so an array declared to hold forty
+bits in `u8` elements actually exposes all sixty-four of them. This module
+provides [`BitArrayExact`], which carries its bit-count as a third type
+parameter and only ever exposes that many bits of the buffer.
+
+The bit-count cannot be a `const` parameter until the compiler permits numeric
+type parameters in type-level expressions (see the note in the parent module).
+Instead, it is encoded in the *size* of a marker type `L`, which is never
+instantiated: `[u8; N]` produces a length of `N` bits. The [`BitArr!`] macro
+produces these types from a bit-count, and should be preferred to writing them
+out by hand.
+
+[`BitArr!`]: macro@crate::BitArr
+[`BitArray`]: crate::array::BitArray
+[`BitArrayExact`]: self::BitArrayExact
+!*/
+
+use crate::{
+	array::BitArray,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	view::BitView,
+};
+
+use core::{
+	borrow::{
+		Borrow,
+		BorrowMut,
+	},
+	cmp,
+	fmt::{
+		self,
+		Binary,
+		Debug,
+		Display,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	marker::PhantomData,
+	mem,
+	ops::{
+		Deref,
+		DerefMut,
+	},
+};
+
+/** A fixed-size array of exactly `L`-many bits.
+
+This wraps a [`BitArray`] buffer, but restricts all of its [`BitSlice`] views to
+the front `mem::size_of::<L>()` bits. The remaining bits of the buffer are dead:
+they are not visible to iteration, counting, comparison, hashing, or
+formatting, and are not guaranteed to hold any particular value.
+
+# Type Parameters
+
+- `O`: The ordering of bits within memory registers.
+- `V`: The buffer type, as in [`BitArray`]. It must be able to hold at least
+  the requested number of bits.
+- `L`: A length marker type, whose size in bytes is the number of live bits.
+  This is always `[u8; N]` when produced by [`BitArr!`].
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut flags: BitArr!(for 40, in Msb0, u8) = BitArrayExact::zeroed();
+assert_eq!(flags.len(), 40);
+assert_eq!(flags.as_buffer().len(), 5);
+
+flags.set_all(true);
+assert_eq!(flags.count_ones(), 40);
+
+let partial: BitArr!(for 12, in Lsb0, u8) = BitArrayExact::new([!0u8; 2]);
+assert_eq!(partial.count_ones(), 12);
+assert_eq!(partial.iter().count(), 12);
+```
+
+[`BitArr!`]: macro@crate::BitArr
+[`BitArray`]: crate::array::BitArray
+[`BitSlice`]: crate::slice::BitSlice
+**/
+#[repr(transparent)]
+#[derive(Copy)]
+pub struct BitArrayExact<O = Lsb0, V = [usize; 1], L = [u8; 0]>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	/// The full buffer, including dead bits.
+	data: BitArray<O, V>,
+	/// The length marker.
+	_len: PhantomData<L>,
+}
+
+impl<O, V, L> BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	/// The number of live bits in the array.
+	pub const LEN: usize = mem::size_of::<L>();
+
+	/// Constructs a new array with its memory set to zero.
+	///
+	/// # Panics
+	///
+	/// This panics if `V` cannot hold [`LEN`] bits.
+	///
+	/// [`LEN`]: Self::LEN
+	pub fn zeroed() -> Self {
+		Self::wrap(BitArray::zeroed())
+	}
+
+	/// Wraps a buffer in an exact-length array.
+	///
+	/// # Parameters
+	///
+	/// - `data`: A buffer of at least [`LEN`] bits. Any bits after the first
+	///   `LEN` bits are dead, and will not be visible through the array.
+	///
+	/// # Panics
+	///
+	/// This panics if `V` cannot hold [`LEN`] bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits: BitArr!(for 10, in Msb0, u8) = BitArrayExact::new([0xFF, 0xC3]);
+	/// assert_eq!(bits, bits![1, 1, 1, 1, 1, 1, 1, 1, 1, 1]);
+	/// ```
+	///
+	/// [`LEN`]: Self::LEN
+	pub fn new(data: V) -> Self {
+		Self::wrap(BitArray::new(data))
+	}
+
+	/// Removes the exact-length wrapper, leaving the full contained buffer.
+	///
+	/// The dead bits after [`LEN`] are returned unmodified.
+	///
+	/// [`LEN`]: Self::LEN
+	pub fn value(self) -> V {
+		self.data.value()
+	}
+
+	/// Removes the length restriction, producing a [`BitArray`] over the full
+	/// buffer.
+	///
+	/// [`BitArray`]: crate::array::BitArray
+	pub fn into_bitarray(self) -> BitArray<O, V> {
+		self.data
+	}
+
+	/// Gets the number of live bits in the array.
+	///
+	/// This is always [`LEN`].
+	///
+	/// [`LEN`]: Self::LEN
+	pub fn len(&self) -> usize {
+		Self::LEN
+	}
+
+	/// Tests if the array has no live bits.
+	pub fn is_empty(&self) -> bool {
+		Self::LEN == 0
+	}
+
+	/// Views the live bits of the array as a [`BitSlice`].
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	pub fn as_bitslice(&self) -> &BitSlice<O, V::Store> {
+		unsafe { self.data.as_bitslice().get_unchecked(.. Self::LEN) }
+	}
+
+	/// Views the live bits of the array as a mutable [`BitSlice`].
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<O, V::Store> {
+		unsafe { self.data.as_mut_bitslice().get_unchecked_mut(.. Self::LEN) }
+	}
+
+	/// Views the interior buffer, including its dead bits.
+	pub fn as_buffer(&self) -> &V {
+		self.data.as_buffer()
+	}
+
+	/// Mutably views the interior buffer, including its dead bits.
+	pub fn as_mut_buffer(&mut self) -> &mut V {
+		self.data.as_mut_buffer()
+	}
+
+	/// Checks the buffer width against the length marker.
+	fn wrap(data: BitArray<O, V>) -> Self {
+		assert!(
+			Self::LEN <= V::const_bits(),
+			"Buffer of {} bits cannot hold {} bits",
+			V::const_bits(),
+			Self::LEN,
+		);
+		Self {
+			data,
+			_len: PhantomData,
+		}
+	}
+}
+
+impl<O, V, L> Borrow<BitSlice<O, V::Store>> for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn borrow(&self) -> &BitSlice<O, V::Store> {
+		self.as_bitslice()
+	}
+}
+
+impl<O, V, L> BorrowMut<BitSlice<O, V::Store>> for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn borrow_mut(&mut self) -> &mut BitSlice<O, V::Store> {
+		self.as_mut_bitslice()
+	}
+}
+
+impl<O, V, L> Clone for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn clone(&self) -> Self {
+		Self {
+			data: self.data.clone(),
+			_len: PhantomData,
+		}
+	}
+}
+
+impl<O, V, L> Eq for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+}
+
+impl<O, V, L> Ord for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn cmp(&self, other: &Self) -> cmp::Ordering {
+		self.as_bitslice().cmp(other.as_bitslice())
+	}
+}
+
+impl<O, V, L, T> PartialEq<BitArrayExact<O, V, L>> for BitSlice<O, T>
+where
+	O: BitOrder,
+	V: BitView,
+	T: BitStore,
+{
+	fn eq(&self, other: &BitArrayExact<O, V, L>) -> bool {
+		self == other.as_bitslice()
+	}
+}
+
+impl<O, V, L, Rhs> PartialEq<Rhs> for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+	Rhs: ?Sized,
+	BitSlice<O, V::Store>: PartialEq<Rhs>,
+{
+	fn eq(&self, other: &Rhs) -> bool {
+		self.as_bitslice() == other
+	}
+}
+
+impl<O, V, L, T> PartialOrd<BitArrayExact<O, V, L>> for BitSlice<O, T>
+where
+	O: BitOrder,
+	V: BitView,
+	T: BitStore,
+{
+	fn partial_cmp(
+		&self,
+		other: &BitArrayExact<O, V, L>,
+	) -> Option<cmp::Ordering>
+	{
+		self.partial_cmp(other.as_bitslice())
+	}
+}
+
+impl<O, V, L, Rhs> PartialOrd<Rhs> for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+	Rhs: ?Sized,
+	BitSlice<O, V::Store>: PartialOrd<Rhs>,
+{
+	fn partial_cmp(&self, other: &Rhs) -> Option<cmp::Ordering> {
+		self.as_bitslice().partial_cmp(other)
+	}
+}
+
+impl<O, V, L> AsRef<BitSlice<O, V::Store>> for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn as_ref(&self) -> &BitSlice<O, V::Store> {
+		self.as_bitslice()
+	}
+}
+
+impl<O, V, L> AsMut<BitSlice<O, V::Store>> for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn as_mut(&mut self) -> &mut BitSlice<O, V::Store> {
+		self.as_mut_bitslice()
+	}
+}
+
+impl<O, V, L> From<V> for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn from(data: V) -> Self {
+		Self::new(data)
+	}
+}
+
+impl<O, V, L> Default for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn default() -> Self {
+		Self::zeroed()
+	}
+}
+
+impl<O, V, L> Binary for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		Binary::fmt(self.as_bitslice(), fmt)
+	}
+}
+
+impl<O, V, L> Debug for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		self.as_bitslice().bitptr().render(fmt, "ArrayExact", None)?;
+		fmt.write_str(" ")?;
+		Display::fmt(self, fmt)
+	}
+}
+
+impl<O, V, L> Display for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		Display::fmt(self.as_bitslice(), fmt)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<O, V, L> Hash for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		self.as_bitslice().hash(hasher)
+	}
+}
+
+impl<'a, O, V, L> IntoIterator for &'a BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	type IntoIter = <&'a BitSlice<O, V::Store> as IntoIterator>::IntoIter;
+	type Item = <&'a BitSlice<O, V::Store> as IntoIterator>::Item;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_bitslice().into_iter()
+	}
+}
+
+impl<'a, O, V, L> IntoIterator for &'a mut BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	type IntoIter = <&'a mut BitSlice<O, V::Store> as IntoIterator>::IntoIter;
+	type Item = <&'a mut BitSlice<O, V::Store> as IntoIterator>::Item;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_mut_bitslice().into_iter()
+	}
+}
+
+impl<O, V, L> Deref for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	type Target = BitSlice<O, V::Store>;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+impl<O, V, L> DerefMut for BitArrayExact<O, V, L>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bitslice()
+	}
+}
@@ -20,6 +20,10 @@ use core::ops::{
 	Index,
 	IndexMut,
 	Not,
+	Shl,
+	ShlAssign,
+	Shr,
+	ShrAssign,
 };
 
 impl<O, V, Rhs> BitAnd<Rhs> for BitArray<O, V>
@@ -157,3 +161,109 @@ where
 		self
 	}
 }
+
+/** Shifts the contents of the array towards index `0`.
+
+This is [`BitSlice::shift_left`], except that shifting by the full length of
+the array or more is permitted, and clears it. The direction is in terms of bit
+indices, not numeric significance. Under [`Msb0`] ordering, this matches the
+integer `<<` operator applied to the underlying buffer read as a big-endian
+number. Under [`Lsb0`] ordering, index `0` is the least significant bit, so on
+a single element this matches the integer `>>` operator instead.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let msb = BitArray::<Msb0, u16>::new(0x0FF0);
+assert_eq!((msb << 4).value(), 0x0FF0 << 4);
+
+let lsb = BitArray::<Lsb0, u16>::new(0x0FF0);
+assert_eq!((lsb << 4).value(), 0x0FF0 >> 4);
+```
+
+[`BitSlice::shift_left`]: crate::slice::BitSlice::shift_left
+[`Lsb0`]: crate::order::Lsb0
+[`Msb0`]: crate::order::Msb0
+**/
+impl<O, V> Shl<usize> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	type Output = Self;
+
+	fn shl(mut self, by: usize) -> Self::Output {
+		self <<= by;
+		self
+	}
+}
+
+impl<O, V> ShlAssign<usize> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn shl_assign(&mut self, by: usize) {
+		if by >= self.len() {
+			self.set_all(false);
+		}
+		else {
+			self.shift_left(by);
+		}
+	}
+}
+
+/** Shifts the contents of the array towards index `len`.
+
+This is [`BitSlice::shift_right`], except that shifting by the full length of
+the array or more is permitted, and clears it. The direction is in terms of bit
+indices, not numeric significance. Under [`Msb0`] ordering, this matches the
+integer `>>` operator applied to the underlying buffer read as a big-endian
+number. Under [`Lsb0`] ordering, index `0` is the least significant bit, so on
+a single element this matches the integer `<<` operator instead.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let msb = BitArray::<Msb0, u16>::new(0x0FF0);
+assert_eq!((msb >> 4).value(), 0x0FF0 >> 4);
+
+let lsb = BitArray::<Lsb0, u16>::new(0x0FF0);
+assert_eq!((lsb >> 4).value(), 0x0FF0 << 4);
+```
+
+[`BitSlice::shift_right`]: crate::slice::BitSlice::shift_right
+[`Lsb0`]: crate::order::Lsb0
+[`Msb0`]: crate::order::Msb0
+**/
+impl<O, V> Shr<usize> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	type Output = Self;
+
+	fn shr(mut self, by: usize) -> Self::Output {
+		self >>= by;
+		self
+	}
+}
+
+impl<O, V> ShrAssign<usize> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn shr_assign(&mut self, by: usize) {
+		if by >= self.len() {
+			self.set_all(false);
+		}
+		else {
+			self.shift_right(by);
+		}
+	}
+}
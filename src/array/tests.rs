@@ -135,6 +135,19 @@ fn ops() {
 
 	let _: &BitSlice = &*a;
 	let _: &mut BitSlice = &mut *f;
+
+	let g = bitarr![Msb0, u8; 1, 0, 1, 1, 0, 0, 0, 1];
+	assert_eq!((g << 2).as_slice(), &[0b1011_0001u8 << 2]);
+	assert_eq!((g >> 3).as_slice(), &[0b1011_0001u8 >> 3]);
+	assert!((g << 8).not_any());
+	assert!((g >> 100).not_any());
+
+	let mut h = bitarr![Lsb0, u16; 1; 16];
+	h <<= 4;
+	assert_eq!(h.count_ones(), 12);
+	assert!(h[12 ..].not_any());
+	h >>= 15;
+	assert_eq!(h.as_slice(), &[0x8000]);
 }
 
 #[test]
@@ -195,3 +208,32 @@ fn format() {
 		text
 	);
 }
+
+#[test]
+fn exact() {
+	let mut a: BitArr!(for 40, in Msb0, u8) = BitArrayExact::zeroed();
+	assert_eq!(a.len(), 40);
+	assert_eq!(a.as_buffer().len(), 5);
+	a.set_all(true);
+	assert_eq!(a.count_ones(), 40);
+	assert!(a.all());
+
+	let b: BitArr!(for 12, in Lsb0, u16) = BitArrayExact::new([0xFFFFu16]);
+	let c: BitArr!(for 12, in Lsb0, u16) = BitArrayExact::new([0x0FFFu16]);
+	assert_eq!(b.count_ones(), 12);
+	assert_eq!(b.iter().count(), 12);
+	assert_eq!(b, c);
+	assert_eq!(b.as_bitslice(), bits![1; 12]);
+	assert_ne!(b.value(), c.value());
+	assert_eq!(b.into_bitarray().len(), 16);
+
+	let d: BitArr!(for 70) = Default::default();
+	assert_eq!(d.len(), 70);
+	assert!(d.not_any());
+}
+
+#[test]
+#[should_panic]
+fn exact_too_wide() {
+	let _: BitArrayExact<Lsb0, [u8; 1], [u8; 9]> = BitArrayExact::zeroed();
+}
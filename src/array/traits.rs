@@ -7,6 +7,10 @@ use crate::{
 	},
 	index::BitIdx,
 	order::BitOrder,
+	parse::{
+		self,
+		ParseBitsError,
+	},
 	slice::BitSlice,
 	store::BitStore,
 	view::BitView,
@@ -33,6 +37,7 @@ use core::{
 		Hash,
 		Hasher,
 	},
+	str::FromStr,
 };
 
 impl<O, V> Borrow<BitSlice<O, V::Store>> for BitArray<O, V>
@@ -215,6 +220,44 @@ where
 	}
 }
 
+impl<O, V> FromStr for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	type Err = ParseBitsError;
+
+	/// Parses a bit-array from text, as described in the [`parse`] module.
+	///
+	/// The text must describe exactly as many bits as the array holds.
+	///
+	/// [`parse`]: crate::parse
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		let mut out = Self::zeroed();
+		let mut len = 0;
+		parse::parse(text, |bit| {
+			if let Some(mut slot) = out.get_mut(len) {
+				*slot = bit;
+			}
+			len += 1;
+		})?;
+		ParseBitsError::check_len(out.len(), len)?;
+		Ok(out)
+	}
+}
+
+impl<O, V> TryFrom<&str> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	type Error = ParseBitsError;
+
+	fn try_from(text: &str) -> Result<Self, Self::Error> {
+		text.parse()
+	}
+}
+
 impl<O, V> Default for BitArray<O, V>
 where
 	O: BitOrder,
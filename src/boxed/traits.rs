@@ -3,6 +3,7 @@
 use crate::{
 	boxed::BitBox,
 	order::BitOrder,
+	parse::ParseBitsError,
 	ptr::BitPtr,
 	slice::BitSlice,
 	store::BitStore,
@@ -33,6 +34,7 @@ use core::{
 		Hash,
 		Hasher,
 	},
+	str::FromStr,
 };
 
 use tap::pipe::Pipe;
@@ -240,6 +242,33 @@ where
 	}
 }
 
+impl<O, T> FromStr for BitBox<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Err = ParseBitsError;
+
+	/// Parses a bit-box from text, as described in the [`parse`] module.
+	///
+	/// [`parse`]: crate::parse
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		text.parse().map(BitVec::into_boxed_bitslice)
+	}
+}
+
+impl<O, T> TryFrom<&str> for BitBox<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Error = ParseBitsError;
+
+	fn try_from(text: &str) -> Result<Self, Self::Error> {
+		text.parse()
+	}
+}
+
 impl<O, T> Default for BitBox<O, T>
 where
 	O: BitOrder,
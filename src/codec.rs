@@ -0,0 +1,608 @@
+/*! Variable-length integer codes.
+
+This module provides encoders and decoders for the prefix-free integer codes
+commonly found in media bitstreams and compressed index structures:
+
+- unary: `n` is written as `n` zero bits, followed by a one bit.
+- Elias gamma: `n ≥ 1` is written as `⌊log₂ n⌋` zero bits, followed by `n` in
+  binary.
+- Elias delta: `n ≥ 1` is written as the Elias gamma code of its binary length,
+  followed by `n` in binary without its leading one bit.
+- Exp-Golomb, as used by H.264 and HEVC: unsigned `n` is written as the Elias
+  gamma code of `n + 1`. Signed values are first mapped onto the unsigned codes
+  by the sequence `0, 1, -1, 2, -2, …`.
+- Golomb-Rice with parameter `k`: `n` is written as the unary code of `n >> k`,
+  followed by the `k` low bits of `n`.
+
+Decoders read a code from the front of a [`BitSlice`], and return the decoded
+value along with the number of bits that the code occupied, so that the caller
+can advance past it. The run of zero bits that begins most codes is found with
+[`BitSlice::first_one`], rather than a bit-by-bit walk. Encoders append a code to
+the end of a [`BitVec`], and return the number of bits written.
+
+The binary portions of each code are transferred with [`BitField::load_be`] and
+[`BitField::store_be`], which is the same convention used by [`BitReader`] and
+[`BitWriter`]. With `Msb0` ordering this matches the wire format of the
+published codes, where the most significant bit of each field comes first. Other
+orderings produce codes with the same lengths and prefixes, but with the bits of
+each binary field arranged as `load_be` places them.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::codec;
+
+let mut bv = BitVec::<Msb0, u8>::new();
+codec::encode_exp_golomb(3, &mut bv);
+codec::encode_signed_exp_golomb(-2, &mut bv);
+assert_eq!(bv, bits![0, 0, 1, 0, 0, 0, 0, 1, 0, 1]);
+
+let (value, used) = codec::decode_exp_golomb(&bv).unwrap();
+assert_eq!((value, used), (3, 5));
+let (value, _) = codec::decode_signed_exp_golomb(&bv[used ..]).unwrap();
+assert_eq!(value, -2);
+```
+
+[`BitField::load_be`]: crate::field::BitField::load_be
+[`BitField::store_be`]: crate::field::BitField::store_be
+[`BitReader`]: crate::stream::BitReader
+[`BitSlice`]: crate::slice::BitSlice
+[`BitSlice::first_one`]: crate::slice::BitSlice::first_one
+[`BitVec`]: crate::vec::BitVec
+[`BitWriter`]: crate::stream::BitWriter
+!*/
+
+use crate::{
+	field::BitField,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	stream::StreamError,
+};
+
+use core::convert::TryFrom;
+
+#[cfg(feature = "alloc")]
+use crate::{
+	stream::BitWriter,
+	vec::BitVec,
+};
+
+/// Decodes a unary code from the front of a bit-slice.
+///
+/// # Parameters
+///
+/// - `bits`: A bit-slice beginning with a unary code.
+///
+/// # Returns
+///
+/// The decoded value and the number of bits the code occupied, or an error if
+/// `bits` ends before the terminating one bit.
+pub fn decode_unary<O, T>(
+	bits: &BitSlice<O, T>,
+) -> Result<(u64, usize), StreamError>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let zeros = leading_zeros(bits)?;
+	Ok((zeros as u64, zeros + 1))
+}
+
+/// Appends a unary code to a bit-vector.
+///
+/// # Parameters
+///
+/// - `value`: The value to encode. The code is `value + 1` bits long.
+/// - `out`: The bit-vector to which the code is appended.
+///
+/// # Returns
+///
+/// The number of bits appended.
+#[cfg(feature = "alloc")]
+pub fn encode_unary<O, T>(value: u64, out: &mut BitVec<O, T>) -> usize
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let zeros = value as usize;
+	let len = out.len();
+	out.resize(len + zeros, false);
+	out.push(true);
+	zeros + 1
+}
+
+/// Decodes an Elias gamma code from the front of a bit-slice.
+///
+/// # Parameters
+///
+/// - `bits`: A bit-slice beginning with an Elias gamma code.
+///
+/// # Returns
+///
+/// The decoded value, which is never zero, and the number of bits the code
+/// occupied. This produces an error if `bits` ends before the code does, or if
+/// the code describes a value wider than 64 bits.
+pub fn decode_elias_gamma<O, T>(
+	bits: &BitSlice<O, T>,
+) -> Result<(u64, usize), StreamError>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	let (value, used) = decode_gamma(bits)?;
+	Ok((narrow(value)?, used))
+}
+
+/// Appends an Elias gamma code to a bit-vector.
+///
+/// # Parameters
+///
+/// - `value`: The value to encode.
+/// - `out`: The bit-vector to which the code is appended.
+///
+/// # Returns
+///
+/// The number of bits appended.
+///
+/// # Panics
+///
+/// This panics if `value` is zero, which has no Elias gamma code.
+#[cfg(feature = "alloc")]
+pub fn encode_elias_gamma<O, T>(value: u64, out: &mut BitVec<O, T>) -> usize
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	assert!(value != 0, "Zero has no Elias gamma code");
+	encode_gamma(value as u128, out)
+}
+
+/// Decodes an Elias delta code from the front of a bit-slice.
+///
+/// # Parameters
+///
+/// - `bits`: A bit-slice beginning with an Elias delta code.
+///
+/// # Returns
+///
+/// The decoded value, which is never zero, and the number of bits the code
+/// occupied. This produces an error if `bits` ends before the code does, or if
+/// the code describes a value wider than 64 bits.
+pub fn decode_elias_delta<O, T>(
+	bits: &BitSlice<O, T>,
+) -> Result<(u64, usize), StreamError>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	let (width, head) = decode_gamma(bits)?;
+	if width > 64 {
+		return Err(StreamError::Overflow { width: 64 });
+	}
+	let rest = width as usize - 1;
+	let tail = read_be(&bits[head ..], rest)?;
+	Ok(((1 << rest) | tail as u64, head + rest))
+}
+
+/// Appends an Elias delta code to a bit-vector.
+///
+/// # Parameters
+///
+/// - `value`: The value to encode.
+/// - `out`: The bit-vector to which the code is appended.
+///
+/// # Returns
+///
+/// The number of bits appended.
+///
+/// # Panics
+///
+/// This panics if `value` is zero, which has no Elias delta code.
+#[cfg(feature = "alloc")]
+pub fn encode_elias_delta<O, T>(value: u64, out: &mut BitVec<O, T>) -> usize
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	assert!(value != 0, "Zero has no Elias delta code");
+	let rest = 63 - value.leading_zeros() as usize;
+	let head = encode_gamma(rest as u128 + 1, out);
+	write_be(value as u128, rest, out);
+	head + rest
+}
+
+/// Decodes an unsigned Exp-Golomb code, `ue(v)`, from the front of a
+/// bit-slice.
+///
+/// # Parameters
+///
+/// - `bits`: A bit-slice beginning with an Exp-Golomb code.
+///
+/// # Returns
+///
+/// The decoded value and the number of bits the code occupied. This produces an
+/// error if `bits` ends before the code does, or if the code describes a value
+/// wider than 64 bits.
+pub fn decode_exp_golomb<O, T>(
+	bits: &BitSlice<O, T>,
+) -> Result<(u64, usize), StreamError>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	let (value, used) = decode_gamma(bits)?;
+	Ok((narrow(value - 1)?, used))
+}
+
+/// Appends an unsigned Exp-Golomb code, `ue(v)`, to a bit-vector.
+///
+/// # Parameters
+///
+/// - `value`: The value to encode.
+/// - `out`: The bit-vector to which the code is appended.
+///
+/// # Returns
+///
+/// The number of bits appended.
+#[cfg(feature = "alloc")]
+pub fn encode_exp_golomb<O, T>(value: u64, out: &mut BitVec<O, T>) -> usize
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	encode_gamma(value as u128 + 1, out)
+}
+
+/// Decodes a signed Exp-Golomb code, `se(v)`, from the front of a bit-slice.
+///
+/// # Parameters
+///
+/// - `bits`: A bit-slice beginning with an Exp-Golomb code.
+///
+/// # Returns
+///
+/// The decoded value and the number of bits the code occupied. This produces an
+/// error if `bits` ends before the code does, or if the code describes a value
+/// outside the range of `i64`.
+pub fn decode_signed_exp_golomb<O, T>(
+	bits: &BitSlice<O, T>,
+) -> Result<(i64, usize), StreamError>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	let (code, used) = decode_gamma(bits)?;
+	//  `code` is one greater than the mapped value, so odd codes are negative.
+	let half = (code / 2) as i128;
+	let value = if code & 1 == 0 { half } else { -half };
+	i64::try_from(value)
+		.map(|value| (value, used))
+		.map_err(|_| StreamError::Overflow { width: 64 })
+}
+
+/// Appends a signed Exp-Golomb code, `se(v)`, to a bit-vector.
+///
+/// # Parameters
+///
+/// - `value`: The value to encode.
+/// - `out`: The bit-vector to which the code is appended.
+///
+/// # Returns
+///
+/// The number of bits appended.
+#[cfg(feature = "alloc")]
+pub fn encode_signed_exp_golomb<O, T>(
+	value: i64,
+	out: &mut BitVec<O, T>,
+) -> usize
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	let value = value as i128;
+	let code = if value > 0 { 2 * value - 1 } else { -2 * value };
+	encode_gamma(code as u128 + 1, out)
+}
+
+/// Decodes a Golomb-Rice code from the front of a bit-slice.
+///
+/// # Parameters
+///
+/// - `bits`: A bit-slice beginning with a Golomb-Rice code.
+/// - `k`: The code parameter. The remainder field of the code is `k` bits
+///   wide.
+///
+/// # Returns
+///
+/// The decoded value and the number of bits the code occupied. This produces an
+/// error if `bits` ends before the code does, or if the code describes a value
+/// wider than 64 bits.
+pub fn decode_rice<O, T>(
+	bits: &BitSlice<O, T>,
+	k: u8,
+) -> Result<(u64, usize), StreamError>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	let k = k as usize;
+	if k > 64 {
+		return Err(StreamError::TooWide {
+			requested: k,
+			width: 64,
+		});
+	}
+	let (quot, head) = decode_unary(bits)?;
+	let rem = read_be(&bits[head ..], k)?;
+	let value = (quot as u128)
+		.checked_shl(k as u32)
+		.filter(|value| value >> k == quot as u128)
+		.ok_or(StreamError::Overflow { width: 64 })?;
+	Ok((narrow(value | rem)?, head + k))
+}
+
+/// Appends a Golomb-Rice code to a bit-vector.
+///
+/// The quotient `value >> k` is written in unary, so `k` must be chosen such
+/// that this is small.
+///
+/// # Parameters
+///
+/// - `value`: The value to encode.
+/// - `k`: The code parameter. The remainder field of the code is `k` bits
+///   wide.
+/// - `out`: The bit-vector to which the code is appended.
+///
+/// # Returns
+///
+/// The number of bits appended.
+///
+/// # Panics
+///
+/// This panics if `k` is greater than 64.
+#[cfg(feature = "alloc")]
+pub fn encode_rice<O, T>(value: u64, k: u8, out: &mut BitVec<O, T>) -> usize
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	assert!(k <= 64, "Rice parameter {} is wider than 64 bits", k);
+	let head = encode_unary(((value as u128) >> k) as u64, out);
+	write_be(value as u128, k as usize, out);
+	head + k as usize
+}
+
+/// Counts the zeros before the first one bit in a code prefix.
+fn leading_zeros<O, T>(bits: &BitSlice<O, T>) -> Result<usize, StreamError>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	bits.first_one().ok_or(StreamError::Underrun {
+		requested: bits.len() + 1,
+		remaining: bits.len(),
+	})
+}
+
+/// Loads a big-endian field of up to 128 bits, which may be empty.
+fn read_be<O, T>(bits: &BitSlice<O, T>, width: usize) -> Result<u128, StreamError>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	if width > bits.len() {
+		return Err(StreamError::Underrun {
+			requested: width,
+			remaining: bits.len(),
+		});
+	}
+	if width == 0 {
+		return Ok(0);
+	}
+	Ok(bits[.. width].load_be::<u128>())
+}
+
+/// Decodes the Elias gamma code of a value of up to 65 bits.
+///
+/// The leading one bit of the value is found by the zero-run scan, and only the
+/// bits below it are loaded, so that the code stays prefix-free under every
+/// ordering.
+fn decode_gamma<O, T>(
+	bits: &BitSlice<O, T>,
+) -> Result<(u128, usize), StreamError>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	let zeros = leading_zeros(bits)?;
+	//  Exp-Golomb codes of 64-bit values can carry a 65-bit field.
+	if zeros > 64 {
+		return Err(StreamError::Overflow { width: 64 });
+	}
+	let low = read_be(&bits[zeros + 1 ..], zeros).map_err(|_| {
+		StreamError::Underrun {
+			requested: 2 * zeros + 1,
+			remaining: bits.len(),
+		}
+	})?;
+	Ok(((1 << zeros) | low, 2 * zeros + 1))
+}
+
+/// Narrows a decoded value to 64 bits.
+fn narrow(value: u128) -> Result<u64, StreamError> {
+	u64::try_from(value).map_err(|_| StreamError::Overflow { width: 64 })
+}
+
+/// Appends the Elias gamma code of a non-zero value of up to 65 bits.
+#[cfg(feature = "alloc")]
+fn encode_gamma<O, T>(value: u128, out: &mut BitVec<O, T>) -> usize
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	let zeros = 127 - value.leading_zeros() as usize;
+	let len = out.len();
+	out.resize(len + zeros, false);
+	out.push(true);
+	write_be(value, zeros, out);
+	2 * zeros + 1
+}
+
+/// Appends the low `width` bits of a value, which may be zero bits.
+#[cfg(feature = "alloc")]
+fn write_be<O, T>(value: u128, width: usize, out: &mut BitVec<O, T>)
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	BitWriter::new(out)
+		.write_bits(value, width)
+		.expect("Code fields never exceed 128 bits");
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn known_codes() {
+		let mut bv = BitVec::<Msb0, u8>::new();
+		for value in 0 .. 4 {
+			encode_exp_golomb(value, &mut bv);
+		}
+		assert_eq!(bv, bits![1, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 0]);
+
+		bv.clear();
+		for &value in &[0, 1, -1, 2] {
+			encode_signed_exp_golomb(value, &mut bv);
+		}
+		assert_eq!(bv, bits![1, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 0]);
+
+		bv.clear();
+		assert_eq!(encode_elias_gamma(5, &mut bv), 5);
+		assert_eq!(encode_elias_delta(10, &mut bv), 8);
+		assert_eq!(encode_rice(9, 2, &mut bv), 5);
+		assert_eq!(encode_unary(2, &mut bv), 3);
+		assert_eq!(bv, bits![
+			0, 0, 1, 0, 1, // gamma(5)
+			0, 0, 1, 0, 0, 0, 1, 0, // delta(10)
+			0, 0, 1, 0, 1, // rice(9, 2)
+			0, 0, 1, // unary(2)
+		]);
+	}
+
+	#[test]
+	fn round_trip() {
+		let values = [
+			0u64,
+			1,
+			2,
+			3,
+			7,
+			8,
+			255,
+			256,
+			0xDEAD_BEEF,
+			u64::MAX >> 1,
+			u64::MAX - 1,
+			u64::MAX,
+		];
+
+		let mut bv = BitVec::<Lsb0, u16>::new();
+		let mut expected = Vec::new();
+		for &value in &values {
+			let signed = value as i64;
+			let len = encode_exp_golomb(value, &mut bv);
+			expected.push((value as i128, len));
+			let len = encode_signed_exp_golomb(signed, &mut bv);
+			expected.push((signed as i128, len));
+			if value != 0 {
+				let len = encode_elias_gamma(value, &mut bv);
+				expected.push((value as i128, len));
+				let len = encode_elias_delta(value, &mut bv);
+				expected.push((value as i128, len));
+			}
+			let len = encode_rice(value, 60, &mut bv);
+			expected.push((value as i128, len));
+		}
+		let len = encode_signed_exp_golomb(i64::MIN, &mut bv);
+		expected.push((i64::MIN as i128, len));
+		let len = encode_unary(70, &mut bv);
+		expected.push((70, len));
+
+		let mut rest = bv.as_bitslice();
+		let mut expect = expected.iter();
+		let mut check = |(value, used): (i128, usize)| {
+			assert_eq!(Some(&(value, used)), expect.next());
+			used
+		};
+		for &value in &values {
+			let used = check(decode_exp_golomb(rest).map(widen).unwrap());
+			rest = &rest[used ..];
+			let (signed, used) = decode_signed_exp_golomb(rest).unwrap();
+			let used = check((signed as i128, used));
+			rest = &rest[used ..];
+			if value != 0 {
+				let used = check(decode_elias_gamma(rest).map(widen).unwrap());
+				rest = &rest[used ..];
+				let used = check(decode_elias_delta(rest).map(widen).unwrap());
+				rest = &rest[used ..];
+			}
+			let used = check(decode_rice(rest, 60).map(widen).unwrap());
+			rest = &rest[used ..];
+		}
+		let (min, used) = decode_signed_exp_golomb(rest).unwrap();
+		let used = check((min as i128, used));
+		rest = &rest[used ..];
+		let used = check(decode_unary(rest).map(widen).unwrap());
+		assert_eq!(used, rest.len());
+	}
+
+	fn widen((value, used): (u64, usize)) -> (i128, usize) {
+		(value as i128, used)
+	}
+
+	#[test]
+	fn errors() {
+		assert_eq!(
+			decode_exp_golomb(bits![0, 0, 0]),
+			Err(StreamError::Underrun {
+				requested: 4,
+				remaining: 3,
+			})
+		);
+		assert_eq!(
+			decode_elias_gamma(bits![0, 0, 1, 0]),
+			Err(StreamError::Underrun {
+				requested: 5,
+				remaining: 4,
+			})
+		);
+		assert!(decode_rice(bits![1, 0], 3).is_err());
+
+		let mut bv = bitvec![0; 65];
+		bv.push(true);
+		bv.resize(131, true);
+		assert_eq!(
+			decode_exp_golomb(&bv),
+			Err(StreamError::Overflow { width: 64 })
+		);
+	}
+}
@@ -0,0 +1,524 @@
+/*! Lazy bitwise combinators over bit-slices.
+
+The bitwise operators on [`BitSlice`] and its owning containers all write their
+result into the left-hand operand, so an expression such as `(a & b) | !c` over
+three read-only slices needs a new allocation for each intermediate value. This
+module provides [`BitExpr`] expression trees which describe such a computation
+without performing it. The tree is evaluated once, a processor word at a time,
+when it is counted, iterated, or written into a destination.
+
+Slices are read a word at a time through the [`Lsb0`] and [`Msb0`] batch loads
+regardless of whether the operands share an ordering or storage type, so mixed
+layouts can be freely combined. Slices with other orderings are read bit by bit.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::combine::BitExpr;
+
+let a = bits![Msb0, u8; 1, 1, 0, 0, 1, 0];
+let b = bits![Lsb0, u16; 1, 0, 1, 0, 1, 0];
+let c = bits![Msb0, u32; 0, 0, 1, 1, 0, 1];
+
+let expr = a.zip_and(b).or(c.invert());
+assert_eq!(expr.count_ones(), 3);
+assert!(expr.iter().eq(bits![1, 1, 0, 0, 1, 0].iter().copied()));
+```
+
+[`BitExpr`]: self::BitExpr
+[`BitSlice`]: crate::slice::BitSlice
+[`Lsb0`]: crate::order::Lsb0
+[`Msb0`]: crate::order::Msb0
+!*/
+
+use crate::{
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::{
+	cmp,
+	iter::FusedIterator,
+};
+
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+
+/// The number of bits evaluated at once.
+const WORD: usize = <usize as BitMemory>::BITS as usize;
+
+/** A lazily-evaluated sequence of bits.
+
+This is implemented by read-only bit-slice references, which form the leaves of
+an expression, and by the combinator types in this module. The combinator
+methods consume their operands, which are cheap to copy, and produce a new
+expression node. None of them perform any work until the expression is
+evaluated.
+
+Binary combinators truncate to the shorter of their two operands, as
+[`Iterator::zip`] does.
+
+[`Iterator::zip`]: core::iter::Iterator::zip
+**/
+pub trait BitExpr: Sized {
+	/// Gets the number of bits the expression produces.
+	fn len(&self) -> usize;
+
+	/// Evaluates a span of the expression.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `start`: The index of the first bit to evaluate.
+	/// - `width`: The number of bits to evaluate. This is never more than the
+	///   bit width of `usize`, and `start + width` is never more than
+	///   `self.len()`.
+	///
+	/// # Returns
+	///
+	/// The `width` bits of the expression beginning at `start`, with the bit
+	/// at `start` in the least significant position. The bits above `width`
+	/// may take any value.
+	fn load_word(&self, start: usize, width: usize) -> usize;
+
+	/// Tests if the expression produces no bits.
+	fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Combines the expression with another by Boolean AND.
+	fn and<R>(self, rhs: R) -> ZipAnd<Self, R>
+	where R: BitExpr {
+		ZipAnd::new(self, rhs)
+	}
+
+	/// Combines the expression with another by Boolean OR.
+	fn or<R>(self, rhs: R) -> ZipOr<Self, R>
+	where R: BitExpr {
+		ZipOr::new(self, rhs)
+	}
+
+	/// Combines the expression with another by Boolean XOR.
+	fn xor<R>(self, rhs: R) -> ZipXor<Self, R>
+	where R: BitExpr {
+		ZipXor::new(self, rhs)
+	}
+
+	/// Inverts each bit of the expression.
+	fn invert(self) -> Invert<Self> {
+		Invert { inner: self }
+	}
+
+	/// Counts the number of bits the expression sets to `1`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::combine::BitExpr;
+	///
+	/// let a = bits![1; 100];
+	/// let b = bits![Msb0, u8; 0; 100];
+	/// assert_eq!(a.zip_xor(b).count_ones(), 100);
+	/// assert_eq!(a.zip_and(b).count_ones(), 0);
+	/// ```
+	fn count_ones(&self) -> usize {
+		self.words()
+			.map(|(word, width)| (word & mask(width)).count_ones() as usize)
+			.sum()
+	}
+
+	/// Counts the number of bits the expression clears to `0`.
+	fn count_zeros(&self) -> usize {
+		self.len() - self.count_ones()
+	}
+
+	/// Tests if any bit of the expression is set to `1`.
+	///
+	/// This stops evaluating at the first word containing a set bit.
+	fn any(&self) -> bool {
+		self.words().any(|(word, width)| word & mask(width) != 0)
+	}
+
+	/// Tests if every bit of the expression is set to `1`.
+	///
+	/// This stops evaluating at the first word containing a cleared bit.
+	fn all(&self) -> bool {
+		self.words()
+			.all(|(word, width)| word & mask(width) == mask(width))
+	}
+
+	/// Iterates over each bit of the expression.
+	///
+	/// The expression is evaluated a word at a time, as the iterator reaches
+	/// each word. The iterator can be collected into any container that
+	/// accepts `bool`s, including [`BitVec`].
+	///
+	/// [`BitVec`]: crate::vec::BitVec
+	fn iter(&self) -> Bits<'_, Self> {
+		Bits {
+			expr: self,
+			head: 0,
+			len: self.len(),
+			word: 0,
+		}
+	}
+
+	/// Evaluates the expression into a bit-slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `dst`: A bit-slice of exactly `self.len()` bits.
+	///
+	/// # Panics
+	///
+	/// This panics if `dst` is not the same length as `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::combine::BitExpr;
+	///
+	/// let a = bits![0, 1, 0, 1];
+	/// let b = bits![0, 0, 1, 1];
+	/// let mut out = bitarr![Msb0, u8; 0; 8];
+	/// a.zip_or(b).write_to(&mut out[.. 4]);
+	/// assert_eq!(out.as_slice(), &[0b0111_0000]);
+	/// ```
+	fn write_to<O, T>(&self, dst: &mut BitSlice<O, T>)
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		assert_eq!(
+			self.len(),
+			dst.len(),
+			"Cannot write an expression of {} bits into a slice of {} bits",
+			self.len(),
+			dst.len(),
+		);
+		for (idx, chunk) in dst.chunks_mut(WORD).enumerate() {
+			let width = chunk.len();
+			chunk.store_ordered(self.load_word(idx * WORD, width));
+		}
+	}
+
+	/// Evaluates the expression into a new bit-vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::combine::BitExpr;
+	///
+	/// let a = bits![0, 1, 0, 1];
+	/// let bv = a.invert().to_bitvec::<Lsb0, u8>();
+	/// assert_eq!(bv, bits![1, 0, 1, 0]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	fn to_bitvec<O, T>(&self) -> BitVec<O, T>
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let mut out = BitVec::repeat(false, self.len());
+		self.write_to(out.as_mut_bitslice());
+		out
+	}
+
+	#[doc(hidden)]
+	fn words(&self) -> Words<'_, Self> {
+		Words {
+			expr: self,
+			head: 0,
+		}
+	}
+}
+
+impl<O, T> BitExpr for &BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn len(&self) -> usize {
+		BitSlice::len(self)
+	}
+
+	fn load_word(&self, start: usize, width: usize) -> usize {
+		unsafe { self.get_unchecked(start .. start + width) }.load_ordered()
+	}
+}
+
+macro_rules! zip {
+	($($(#[$attr:meta])* $t:ident => $op:tt);+ $(;)?) => { $(
+		$(#[$attr])*
+		#[derive(Clone, Copy, Debug)]
+		pub struct $t<A, B>
+		where
+			A: BitExpr,
+			B: BitExpr,
+		{
+			/// The left operand.
+			lhs: A,
+			/// The right operand.
+			rhs: B,
+			/// The length of the shorter operand.
+			len: usize,
+		}
+
+		impl<A, B> $t<A, B>
+		where
+			A: BitExpr,
+			B: BitExpr,
+		{
+			fn new(lhs: A, rhs: B) -> Self {
+				let len = cmp::min(lhs.len(), rhs.len());
+				Self { lhs, rhs, len }
+			}
+		}
+
+		impl<A, B> BitExpr for $t<A, B>
+		where
+			A: BitExpr,
+			B: BitExpr,
+		{
+			fn len(&self) -> usize {
+				self.len
+			}
+
+			fn load_word(&self, start: usize, width: usize) -> usize {
+				self.lhs.load_word(start, width)
+					$op self.rhs.load_word(start, width)
+			}
+		}
+	)+ };
+}
+
+zip! {
+	/// Combines two expressions by Boolean AND.
+	ZipAnd => &;
+	/// Combines two expressions by Boolean OR.
+	ZipOr => |;
+	/// Combines two expressions by Boolean XOR.
+	ZipXor => ^;
+}
+
+/// Inverts each bit of an expression.
+#[derive(Clone, Copy, Debug)]
+pub struct Invert<A>
+where A: BitExpr
+{
+	/// The inverted expression.
+	inner: A,
+}
+
+impl<A> BitExpr for Invert<A>
+where A: BitExpr
+{
+	fn len(&self) -> usize {
+		self.inner.len()
+	}
+
+	fn load_word(&self, start: usize, width: usize) -> usize {
+		!self.inner.load_word(start, width)
+	}
+}
+
+/** Iterates over each bit of an expression.
+
+This type is produced by [`BitExpr::iter`].
+
+[`BitExpr::iter`]: crate::combine::BitExpr::iter
+**/
+#[derive(Clone, Debug)]
+pub struct Bits<'a, E>
+where E: BitExpr
+{
+	/// The expression being evaluated.
+	expr: &'a E,
+	/// The index of the next bit to yield.
+	head: usize,
+	/// The length of the expression.
+	len: usize,
+	/// The evaluated word containing `head`, shifted so that `head` is in the
+	/// least significant position.
+	word: usize,
+}
+
+impl<E> Iterator for Bits<'_, E>
+where E: BitExpr
+{
+	type Item = bool;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.head == self.len {
+			return None;
+		}
+		if self.head & (WORD - 1) == 0 {
+			let width = cmp::min(WORD, self.len - self.head);
+			self.word = self.expr.load_word(self.head, width);
+		}
+		let bit = self.word & 1 == 1;
+		self.word >>= 1;
+		self.head += 1;
+		Some(bit)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let rem = self.len - self.head;
+		(rem, Some(rem))
+	}
+}
+
+impl<E> ExactSizeIterator for Bits<'_, E> where E: BitExpr
+{
+}
+
+impl<E> FusedIterator for Bits<'_, E> where E: BitExpr
+{
+}
+
+/// Evaluates an expression a word at a time, yielding each word and the
+/// number of live bits in it.
+#[doc(hidden)]
+#[derive(Clone, Debug)]
+pub struct Words<'a, E>
+where E: BitExpr
+{
+	/// The expression being evaluated.
+	expr: &'a E,
+	/// The index of the next word to evaluate.
+	head: usize,
+}
+
+impl<E> Iterator for Words<'_, E>
+where E: BitExpr
+{
+	type Item = (usize, usize);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let len = self.expr.len();
+		if self.head >= len {
+			return None;
+		}
+		let width = cmp::min(WORD, len - self.head);
+		let word = self.expr.load_word(self.head, width);
+		self.head += width;
+		Some((word, width))
+	}
+}
+
+/// Produces a mask over the low `width` bits of a word.
+fn mask(width: usize) -> usize {
+	!0 >> (WORD - width)
+}
+
+/// Methods that begin lazy bitwise expressions.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Begins a lazy Boolean AND of `self` with another expression.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `rhs`: Any [`BitExpr`], including another `&BitSlice` of any type
+	///   parameters.
+	///
+	/// # Returns
+	///
+	/// An expression that evaluates `self & rhs` bit-wise when it is consumed.
+	/// See the [`combine`] module for details.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::combine::BitExpr;
+	///
+	/// let a = bits![1, 1, 0, 0];
+	/// let b = bits![Msb0, u8; 1, 0, 1, 0];
+	/// assert_eq!(a.zip_and(b).to_bitvec::<Lsb0, usize>(), bits![1, 0, 0, 0]);
+	/// ```
+	///
+	/// [`BitExpr`]: crate::combine::BitExpr
+	/// [`combine`]: crate::combine
+	pub fn zip_and<R>(&self, rhs: R) -> ZipAnd<&Self, R>
+	where R: BitExpr {
+		ZipAnd::new(self, rhs)
+	}
+
+	/// Begins a lazy Boolean OR of `self` with another expression.
+	///
+	/// This is the `|` analogue of [`.zip_and()`].
+	///
+	/// [`.zip_and()`]: Self::zip_and
+	pub fn zip_or<R>(&self, rhs: R) -> ZipOr<&Self, R>
+	where R: BitExpr {
+		ZipOr::new(self, rhs)
+	}
+
+	/// Begins a lazy Boolean XOR of `self` with another expression.
+	///
+	/// This is the `^` analogue of [`.zip_and()`].
+	///
+	/// [`.zip_and()`]: Self::zip_and
+	pub fn zip_xor<R>(&self, rhs: R) -> ZipXor<&Self, R>
+	where R: BitExpr {
+		ZipXor::new(self, rhs)
+	}
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn combinators() {
+		let mut a = bitvec![Lsb0, u8; 0; 300];
+		let mut b = bitvec![Msb0, u32; 0; 310];
+		let mut c = bitvec![Lsb0, usize; 0; 300];
+		for idx in 0 .. 300 {
+			a.set(idx, idx % 3 == 0);
+			b.set(idx, idx % 5 < 2);
+			c.set(idx, idx % 7 == 1);
+		}
+		let (a, b, c) = (&a[1 ..], &b[3 ..], &c[2 ..]);
+
+		let expected = a
+			.iter()
+			.zip(b.iter())
+			.zip(c.iter())
+			.map(|((a, b), c)| (*a & *b) | !*c)
+			.collect::<BitVec>();
+
+		let expr = a.zip_and(b).or(c.invert());
+		assert_eq!(expr.len(), 298);
+		assert_eq!(expr.count_ones(), expected.count_ones());
+		assert_eq!(expr.count_zeros(), expected.count_zeros());
+		assert!(expr.iter().eq(expected.iter().copied()));
+		assert_eq!(expr.iter().len(), 298);
+		assert_eq!(expr.to_bitvec::<Msb0, u16>(), expected);
+		assert_eq!(expr.iter().collect::<BitVec>(), expected);
+
+		let xor = a.zip_xor(a);
+		assert!(!xor.any());
+		assert!(xor.invert().all());
+		assert!(a.zip_or(BitSlice::<Lsb0, u8>::empty()).is_empty());
+	}
+
+	#[test]
+	#[should_panic]
+	fn write_mismatch() {
+		let a = bits![0; 10];
+		a.invert().write_to(bits![mut 0; 9]);
+	}
+}
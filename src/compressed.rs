@@ -0,0 +1,1073 @@
+/*! Compressed bit-maps of 32-bit integers.
+
+This module provides [`CompressedBitmap`], a set of `u32` values whose memory use
+is proportional to the structure of its members rather than to their magnitude.
+It follows the layout of the [Roaring] format: the value space is divided into
+chunks of 2<sup>16</sup> values, keyed by the high half of each member, and
+each chunk that has any members is stored in whichever of three forms is
+smallest:
+
+- a sorted array of the low halves of its members, when it has few members;
+- a sorted list of runs of consecutive members, when its members are clustered;
+- a dense 2<sup>16</sup>-bit [`BitArray`], otherwise.
+
+Run boundaries in dense chunks, and in [`BitSlice`]s being compressed, are found
+with [`BitSlice::iter_one_ranges`], which scans a memory element at a time.
+
+[`BitArray`]: crate::array::BitArray
+[`BitSlice`]: crate::slice::BitSlice
+[`BitSlice::iter_one_ranges`]: crate::slice::BitSlice::iter_one_ranges
+[`CompressedBitmap`]: self::CompressedBitmap
+[Roaring]: https://roaringbitmap.org/
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	array::BitArray,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::{
+		BitSlice,
+		IterOnes,
+	},
+	store::BitStore,
+	vec::BitVec,
+};
+
+use alloc::{
+	boxed::Box,
+	vec::Vec,
+};
+
+use core::{
+	cmp::{
+		self,
+		Ordering,
+	},
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	iter::{
+		FromIterator,
+		FusedIterator,
+	},
+	ops::Range,
+	slice,
+};
+
+/// The number of values covered by each chunk.
+const CHUNK: usize = 1 << 16;
+
+/// The most members that a chunk may store in its array form.
+const ARRAY_MAX: usize = 4096;
+
+/// The storage for a chunk in its dense form.
+pub(crate) type Dense = BitArray<Lsb0, [u64; CHUNK / 64]>;
+
+/** A set of `u32` values, compressed in 2<sup>16</sup>-value chunks.
+
+See the [module documentation] for the storage layout.
+
+Insertion and removal keep each chunk in its current form, except that arrays
+which outgrow their limit become dense. The set operations, and construction
+from a [`BitSlice`], choose the smallest form for each chunk they produce;
+[`.optimize()`] re-selects the form of every chunk.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::compressed::CompressedBitmap;
+
+let mut sparse = CompressedBitmap::new();
+sparse.insert(7);
+sparse.insert(3_000_000_000);
+assert_eq!(sparse.len(), 2);
+
+let mut bits = bitvec![0; 200_000];
+bits[100 .. 150_000].set_all(true);
+let dense = CompressedBitmap::from_bitslice(&bits);
+assert_eq!(dense.len(), 149_900);
+assert_eq!(dense.rank(1000), 900);
+
+let both = dense.intersection(&sparse);
+assert!(both.is_empty());
+let either = dense.union(&sparse);
+assert_eq!(either.iter_ones().last(), Some(3_000_000_000));
+assert_eq!(either.difference_bitslice(&bits), sparse);
+```
+
+[`BitSlice`]: crate::slice::BitSlice
+[`.optimize()`]: Self::optimize
+[module documentation]: self
+**/
+#[derive(Clone, Default)]
+pub struct CompressedBitmap {
+	/// The non-empty chunks, sorted by their keys.
+	pub(crate) chunks: Vec<(u16, Container)>,
+}
+
+impl CompressedBitmap {
+	/// Constructs a new, empty, set.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Counts the number of members in the set.
+	pub fn len(&self) -> u64 {
+		self.chunks.iter().map(|(_, chunk)| chunk.len() as u64).sum()
+	}
+
+	/// Tests if the set has no members.
+	pub fn is_empty(&self) -> bool {
+		self.chunks.is_empty()
+	}
+
+	/// Tests if a value is a member of the set.
+	pub fn contains(&self, value: u32) -> bool {
+		let (key, low) = split(value);
+		match self.find(key) {
+			Ok(idx) => self.chunks[idx].1.contains(low),
+			Err(_) => false,
+		}
+	}
+
+	/// Adds a value to the set.
+	///
+	/// # Returns
+	///
+	/// `true` if `value` was not already a member of the set.
+	pub fn insert(&mut self, value: u32) -> bool {
+		let (key, low) = split(value);
+		match self.find(key) {
+			Ok(idx) => self.chunks[idx].1.insert(low),
+			Err(idx) => {
+				let chunk = Container::Array(alloc::vec![low]);
+				self.chunks.insert(idx, (key, chunk));
+				true
+			},
+		}
+	}
+
+	/// Removes a value from the set.
+	///
+	/// # Returns
+	///
+	/// `true` if `value` was a member of the set.
+	pub fn remove(&mut self, value: u32) -> bool {
+		let (key, low) = split(value);
+		let idx = match self.find(key) {
+			Ok(idx) => idx,
+			Err(_) => return false,
+		};
+		let chunk = &mut self.chunks[idx].1;
+		let out = chunk.remove(low);
+		if chunk.is_empty() {
+			self.chunks.remove(idx);
+		}
+		out
+	}
+
+	/// Removes all members from the set.
+	pub fn clear(&mut self) {
+		self.chunks.clear();
+	}
+
+	/// Finds the smallest member of the set.
+	pub fn first(&self) -> Option<u32> {
+		self.chunks
+			.first()
+			.map(|(key, chunk)| join(*key, chunk.first()))
+	}
+
+	/// Finds the largest member of the set.
+	pub fn last(&self) -> Option<u32> {
+		self.chunks
+			.last()
+			.map(|(key, chunk)| join(*key, chunk.last()))
+	}
+
+	/// Iterates over the members of the set, in ascending order.
+	pub fn iter_ones(&self) -> Iter<'_> {
+		Iter {
+			chunks: self.chunks.iter(),
+			current: None,
+		}
+	}
+
+	/// Counts the members of the set that are less than `value`.
+	///
+	/// This only walks the chunk list and the chunk that holds `value`.
+	pub fn rank(&self, value: u32) -> u64 {
+		let (key, low) = split(value);
+		let idx = match self.find(key) {
+			Ok(idx) | Err(idx) => idx,
+		};
+		let below = self.chunks[.. idx]
+			.iter()
+			.map(|(_, chunk)| chunk.len() as u64)
+			.sum::<u64>();
+		match self.chunks.get(idx) {
+			Some((k, chunk)) if *k == key => below + chunk.rank(low) as u64,
+			_ => below,
+		}
+	}
+
+	/// Produces the set of values that are members of `self` or `other`.
+	pub fn union(&self, other: &Self) -> Self {
+		self.merge(other, Op::Or)
+	}
+
+	/// Produces the set of values that are members of both `self` and
+	/// `other`.
+	pub fn intersection(&self, other: &Self) -> Self {
+		self.merge(other, Op::And)
+	}
+
+	/// Produces the set of values that are members of `self` but not of
+	/// `other`.
+	pub fn difference(&self, other: &Self) -> Self {
+		self.merge(other, Op::AndNot)
+	}
+
+	/// Produces the set of values that are members of `self` or are the
+	/// indices of set bits in `bits`.
+	///
+	/// # Panics
+	///
+	/// This panics if `bits` is longer than 2<sup>32</sup> bits.
+	pub fn union_bitslice<O, T>(&self, bits: &BitSlice<O, T>) -> Self
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		self.union(&Self::from_bitslice(bits))
+	}
+
+	/// Produces the set of members of `self` that are the indices of set bits
+	/// in `bits`.
+	///
+	/// Only the regions of `bits` under the chunks of `self` are read.
+	pub fn intersection_bitslice<O, T>(&self, bits: &BitSlice<O, T>) -> Self
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		self.mask(bits, Op::And)
+	}
+
+	/// Produces the set of members of `self` that are not the indices of set
+	/// bits in `bits`.
+	///
+	/// Only the regions of `bits` under the chunks of `self` are read.
+	pub fn difference_bitslice<O, T>(&self, bits: &BitSlice<O, T>) -> Self
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		self.mask(bits, Op::AndNot)
+	}
+
+	/// Re-encodes every chunk in whichever form takes the least memory.
+	pub fn optimize(&mut self) {
+		for (_, chunk) in &mut self.chunks {
+			chunk.optimize();
+		}
+	}
+
+	/// Compresses a bit-slice, taking the index of each set bit as a member.
+	///
+	/// # Panics
+	///
+	/// This panics if `bits` is longer than 2<sup>32</sup> bits.
+	pub fn from_bitslice<O, T>(bits: &BitSlice<O, T>) -> Self
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		assert!(
+			bits.len() as u64 <= 1 << 32,
+			"Cannot compress a bit-slice of {} bits",
+			bits.len(),
+		);
+		let chunks = bits
+			.chunks(CHUNK)
+			.enumerate()
+			.filter_map(|(key, chunk)| {
+				let runs = chunk
+					.iter_one_ranges()
+					.map(|Range { start, end }| (start as u16, (end - 1) as u16))
+					.collect::<Vec<_>>();
+				if runs.is_empty() {
+					return None;
+				}
+				let mut chunk = Container::Runs(runs);
+				chunk.optimize();
+				Some((key as u16, chunk))
+			})
+			.collect();
+		Self { chunks }
+	}
+
+	/// Expands the set into a bit-vector, setting the bit at the index of each
+	/// member.
+	///
+	/// # Returns
+	///
+	/// A bit-vector whose length is one more than the largest member of the
+	/// set.
+	pub fn to_bitvec<O, T>(&self) -> BitVec<O, T>
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let len = self.last().map_or(0, |last| last as usize + 1);
+		let mut out = BitVec::repeat(false, len);
+		for (key, chunk) in &self.chunks {
+			let base = *key as usize * CHUNK;
+			for (first, last) in chunk.runs() {
+				out[base + first as usize ..][..= (last - first) as usize]
+					.set_all(true);
+			}
+		}
+		out
+	}
+
+	/// Reassembles a set from its chunk list.
+	///
+	/// # Returns
+	///
+	/// A description of the first invariant that the chunk list violates, if
+	/// any.
+	#[cfg(feature = "serde")]
+	pub(crate) fn from_chunks(
+		chunks: Vec<(u16, Container)>,
+	) -> Result<Self, &'static str> {
+		if chunks.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+			return Err("chunk keys must be strictly ascending");
+		}
+		for (_, chunk) in &chunks {
+			chunk.validate()?;
+		}
+		Ok(Self { chunks })
+	}
+
+	/// Finds the chunk for a key, or where it would be inserted.
+	fn find(&self, key: u16) -> Result<usize, usize> {
+		self.chunks.binary_search_by_key(&key, |(key, _)| *key)
+	}
+
+	/// Combines the set with the set bits of a bit-slice, walking the chunks of
+	/// the set rather than compressing the bit-slice.
+	///
+	/// `op` must be `Op::And` or `Op::AndNot`, whose results only hold members
+	/// of `self`.
+	fn mask<O, T>(&self, bits: &BitSlice<O, T>, op: Op) -> Self
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let keep = op == Op::And;
+		let chunks = self
+			.chunks
+			.iter()
+			.filter_map(|(key, chunk)| {
+				let base = *key as usize * CHUNK;
+				let window = bits.get(base ..).unwrap_or_else(BitSlice::empty);
+				let window = &window[.. cmp::min(CHUNK, window.len())];
+				let out = match chunk {
+					Container::Array(values) => {
+						let mut out = Container::Array(
+							values
+								.iter()
+								.copied()
+								.filter(|&value| {
+									let idx = value as usize;
+									(idx < window.len() && window[idx]) == keep
+								})
+								.collect(),
+						);
+						out.optimize();
+						out
+					},
+					_ => {
+						let mut dense = Box::new(Dense::zeroed());
+						for Range { start, end } in window.iter_one_ranges() {
+							dense[start .. end].set_all(true);
+						}
+						chunk.combine(&Container::Dense(dense), op)
+					},
+				};
+				if out.is_empty() {
+					None
+				}
+				else {
+					Some((*key, out))
+				}
+			})
+			.collect();
+		Self { chunks }
+	}
+
+	/// Combines two sets a chunk at a time.
+	fn merge(&self, other: &Self, op: Op) -> Self {
+		let mut chunks = Vec::new();
+		let (mut lhs, mut rhs) = (self.chunks.iter(), other.chunks.iter());
+		let (mut a, mut b) = (lhs.next(), rhs.next());
+		loop {
+			let order = match (a, b) {
+				(None, None) => break,
+				(Some(_), None) => Ordering::Less,
+				(None, Some(_)) => Ordering::Greater,
+				(Some((ka, _)), Some((kb, _))) => ka.cmp(kb),
+			};
+			match order {
+				Ordering::Less => {
+					let (key, chunk) = a.unwrap();
+					if op != Op::And {
+						chunks.push((*key, chunk.clone()));
+					}
+					a = lhs.next();
+				},
+				Ordering::Greater => {
+					let (key, chunk) = b.unwrap();
+					if op == Op::Or {
+						chunks.push((*key, chunk.clone()));
+					}
+					b = rhs.next();
+				},
+				Ordering::Equal => {
+					let ((key, ca), (_, cb)) = (a.unwrap(), b.unwrap());
+					let chunk = ca.combine(cb, op);
+					if !chunk.is_empty() {
+						chunks.push((*key, chunk));
+					}
+					a = lhs.next();
+					b = rhs.next();
+				},
+			}
+		}
+		Self { chunks }
+	}
+}
+
+impl Debug for CompressedBitmap {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_set().entries(self.iter_ones()).finish()
+	}
+}
+
+impl Eq for CompressedBitmap {
+}
+
+impl PartialEq for CompressedBitmap {
+	fn eq(&self, other: &Self) -> bool {
+		self.chunks.len() == other.chunks.len()
+			&& self.iter_ones().eq(other.iter_ones())
+	}
+}
+
+impl Extend<u32> for CompressedBitmap {
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = u32> {
+		for value in iter {
+			self.insert(value);
+		}
+	}
+}
+
+impl FromIterator<u32> for CompressedBitmap {
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = u32> {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+impl<O, T> From<&BitSlice<O, T>> for CompressedBitmap
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(bits: &BitSlice<O, T>) -> Self {
+		Self::from_bitslice(bits)
+	}
+}
+
+impl<'a> IntoIterator for &'a CompressedBitmap {
+	type IntoIter = Iter<'a>;
+	type Item = u32;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter_ones()
+	}
+}
+
+/** Enumerates the members of a [`CompressedBitmap`], in ascending order.
+
+This struct is created by the [`.iter_ones()`] method on [`CompressedBitmap`]s.
+
+[`CompressedBitmap`]: self::CompressedBitmap
+[`.iter_ones()`]: self::CompressedBitmap::iter_ones
+**/
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+	/// The chunks not yet visited.
+	chunks: slice::Iter<'a, (u16, Container)>,
+	/// The high half of the members of the current chunk, and its members.
+	current: Option<(u32, Members<'a>)>,
+}
+
+impl Iterator for Iter<'_> {
+	type Item = u32;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some((base, members)) = &mut self.current {
+				if let Some(low) = members.next() {
+					return Some(*base | low as u32);
+				}
+			}
+			let (key, chunk) = self.chunks.next()?;
+			self.current = Some((join(*key, 0), chunk.members()));
+		}
+	}
+}
+
+impl FusedIterator for Iter<'_> {
+}
+
+/// The storage for one chunk of a [`CompressedBitmap`].
+///
+/// [`CompressedBitmap`]: self::CompressedBitmap
+#[derive(Clone, Debug)]
+pub(crate) enum Container {
+	/// The sorted low halves of the members.
+	Array(Vec<u16>),
+	/// The sorted, non-adjacent, inclusive `(first, last)` runs of members.
+	Runs(Vec<(u16, u16)>),
+	/// A bit for each value in the chunk.
+	Dense(Box<Dense>),
+}
+
+impl Container {
+	/// Counts the members of the chunk.
+	fn len(&self) -> usize {
+		match self {
+			Self::Array(values) => values.len(),
+			Self::Runs(runs) => runs
+				.iter()
+				.map(|&(first, last)| (last - first) as usize + 1)
+				.sum(),
+			Self::Dense(bits) => bits.count_ones(),
+		}
+	}
+
+	/// Tests if the chunk has no members.
+	fn is_empty(&self) -> bool {
+		match self {
+			Self::Array(values) => values.is_empty(),
+			Self::Runs(runs) => runs.is_empty(),
+			Self::Dense(bits) => bits.not_any(),
+		}
+	}
+
+	/// Gets the smallest member of a non-empty chunk.
+	fn first(&self) -> u16 {
+		match self {
+			Self::Array(values) => values[0],
+			Self::Runs(runs) => runs[0].0,
+			Self::Dense(bits) => bits.first_one().unwrap() as u16,
+		}
+	}
+
+	/// Gets the largest member of a non-empty chunk.
+	fn last(&self) -> u16 {
+		match self {
+			Self::Array(values) => values[values.len() - 1],
+			Self::Runs(runs) => runs[runs.len() - 1].1,
+			Self::Dense(bits) => bits.last_one().unwrap() as u16,
+		}
+	}
+
+	fn contains(&self, value: u16) -> bool {
+		match self {
+			Self::Array(values) => values.binary_search(&value).is_ok(),
+			Self::Runs(runs) => find_run(runs, value).is_ok(),
+			Self::Dense(bits) => bits[value as usize],
+		}
+	}
+
+	fn insert(&mut self, value: u16) -> bool {
+		match self {
+			Self::Array(values) => match values.binary_search(&value) {
+				Ok(_) => return false,
+				Err(idx) => values.insert(idx, value),
+			},
+			Self::Runs(runs) => match find_run(runs, value) {
+				Ok(_) => return false,
+				Err(idx) => insert_run(runs, idx, value),
+			},
+			Self::Dense(bits) => {
+				let old = bits[value as usize];
+				bits.set(value as usize, true);
+				return !old;
+			},
+		}
+		if matches!(self, Self::Array(values) if values.len() > ARRAY_MAX) {
+			*self = Self::Dense(self.to_dense());
+		}
+		true
+	}
+
+	fn remove(&mut self, value: u16) -> bool {
+		match self {
+			Self::Array(values) => match values.binary_search(&value) {
+				Ok(idx) => {
+					values.remove(idx);
+					true
+				},
+				Err(_) => false,
+			},
+			Self::Runs(runs) => match find_run(runs, value) {
+				Ok(idx) => {
+					let (first, last) = runs[idx];
+					if first == last {
+						runs.remove(idx);
+					}
+					else if value == first {
+						runs[idx].0 = value + 1;
+					}
+					else if value == last {
+						runs[idx].1 = value - 1;
+					}
+					else {
+						runs[idx].1 = value - 1;
+						runs.insert(idx + 1, (value + 1, last));
+					}
+					true
+				},
+				Err(_) => false,
+			},
+			Self::Dense(bits) => {
+				let old = bits[value as usize];
+				bits.set(value as usize, false);
+				old
+			},
+		}
+	}
+
+	/// Counts the members of the chunk that are less than `value`.
+	fn rank(&self, value: u16) -> usize {
+		match self {
+			Self::Array(values) => match values.binary_search(&value) {
+				Ok(idx) | Err(idx) => idx,
+			},
+			Self::Runs(runs) => runs
+				.iter()
+				.take_while(|&&(first, _)| first < value)
+				.map(|&(first, last)| {
+					cmp::min(last as usize + 1, value as usize) - first as usize
+				})
+				.sum(),
+			Self::Dense(bits) => bits[.. value as usize].count_ones(),
+		}
+	}
+
+	/// Iterates over the members of the chunk.
+	fn members(&self) -> Members<'_> {
+		match self {
+			Self::Array(values) => Members::Array(values.iter()),
+			Self::Runs(runs) => Members::Runs(runs.iter(), 0 .. 0),
+			Self::Dense(bits) => Members::Dense(bits.iter_ones()),
+		}
+	}
+
+	/// Collects the inclusive runs of members in the chunk.
+	fn runs(&self) -> Vec<(u16, u16)> {
+		match self {
+			Self::Runs(runs) => runs.clone(),
+			Self::Dense(bits) => bits
+				.iter_one_ranges()
+				.map(|Range { start, end }| (start as u16, (end - 1) as u16))
+				.collect(),
+			Self::Array(values) => {
+				let mut runs: Vec<(u16, u16)> = Vec::new();
+				for &value in values {
+					match runs.last_mut() {
+						Some(run) if run.1 as u32 + 1 == value as u32 => {
+							run.1 = value;
+						},
+						_ => runs.push((value, value)),
+					}
+				}
+				runs
+			},
+		}
+	}
+
+	/// Counts the runs of members in the chunk.
+	fn count_runs(&self) -> usize {
+		match self {
+			Self::Array(values) if values.is_empty() => 0,
+			Self::Array(values) => {
+				let breaks = values.windows(2).filter(|w| w[0] + 1 != w[1]);
+				1 + breaks.count()
+			},
+			Self::Runs(runs) => runs.len(),
+			Self::Dense(bits) => bits.iter_one_ranges().count(),
+		}
+	}
+
+	/// Copies the chunk into its dense form.
+	fn to_dense(&self) -> Box<Dense> {
+		let mut out = Box::new(Dense::zeroed());
+		match self {
+			Self::Array(values) => {
+				for &value in values {
+					out.set(value as usize, true);
+				}
+			},
+			Self::Runs(runs) => {
+				for &(first, last) in runs {
+					out[first as usize ..= last as usize].set_all(true);
+				}
+			},
+			Self::Dense(bits) => return bits.clone(),
+		}
+		out
+	}
+
+	/// Re-encodes the chunk in whichever form takes the least memory.
+	fn optimize(&mut self) {
+		let (len, runs) = (self.len(), self.count_runs());
+		//  Sizes in bytes of each form.
+		let as_runs = runs * 4;
+		let as_array = if len <= ARRAY_MAX { len * 2 } else { usize::MAX };
+		let as_dense = CHUNK / 8;
+		if as_runs < cmp::min(as_array, as_dense) {
+			if !matches!(self, Self::Runs(_)) {
+				*self = Self::Runs(self.runs());
+			}
+		}
+		else if as_array <= as_dense {
+			if !matches!(self, Self::Array(_)) {
+				*self = Self::Array(self.members().collect());
+			}
+		}
+		else if !matches!(self, Self::Dense(_)) {
+			*self = Self::Dense(self.to_dense());
+		}
+	}
+
+	/// Applies a set operation to two chunks with the same key.
+	fn combine(&self, other: &Self, op: Op) -> Self {
+		let filter = |values: &[u16], rhs: &Self, keep: bool| {
+			values
+				.iter()
+				.copied()
+				.filter(|&value| rhs.contains(value) == keep)
+				.collect()
+		};
+		let mut out = match (op, self, other) {
+			(Op::Or, Self::Array(a), Self::Array(b)) => Self::Array(union(a, b)),
+			(Op::And, Self::Array(a), _) => Self::Array(filter(a, other, true)),
+			(Op::And, _, Self::Array(b)) => Self::Array(filter(b, self, true)),
+			(Op::AndNot, Self::Array(a), _) => {
+				Self::Array(filter(a, other, false))
+			},
+			_ => {
+				let mut bits = self.to_dense();
+				let tmp;
+				let rhs = match other {
+					Self::Dense(dense) => dense,
+					_ => {
+						tmp = other.to_dense();
+						&tmp
+					},
+				};
+				for (a, b) in bits.as_mut_slice().iter_mut().zip(rhs.as_slice())
+				{
+					*a = op.apply(*a, *b);
+				}
+				Self::Dense(bits)
+			},
+		};
+		out.optimize();
+		out
+	}
+
+	/// Checks the invariants of a deserialized chunk.
+	#[cfg(feature = "serde")]
+	fn validate(&self) -> Result<(), &'static str> {
+		if self.is_empty() {
+			return Err("chunks must not be empty");
+		}
+		match self {
+			Self::Array(values) => {
+				if values.len() > ARRAY_MAX {
+					return Err("array chunks must have at most 4096 members");
+				}
+				if values.windows(2).any(|pair| pair[0] >= pair[1]) {
+					return Err("array chunks must be strictly ascending");
+				}
+			},
+			Self::Runs(runs) => {
+				if runs.iter().any(|&(first, last)| first > last) {
+					return Err("runs must not end before they begin");
+				}
+				if runs
+					.windows(2)
+					.any(|pair| pair[0].1 as u32 + 1 >= pair[1].0 as u32)
+				{
+					return Err("runs must be ascending and not adjacent");
+				}
+			},
+			Self::Dense(_) => {},
+		}
+		Ok(())
+	}
+}
+
+/// Enumerates the low halves of the members of a chunk.
+#[derive(Clone, Debug)]
+enum Members<'a> {
+	Array(slice::Iter<'a, u16>),
+	Runs(slice::Iter<'a, (u16, u16)>, Range<u32>),
+	Dense(IterOnes<'a, Lsb0, u64>),
+}
+
+impl Iterator for Members<'_> {
+	type Item = u16;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self {
+			Self::Array(values) => values.next().copied(),
+			Self::Runs(runs, current) => loop {
+				if let Some(value) = current.next() {
+					return Some(value as u16);
+				}
+				let &(first, last) = runs.next()?;
+				*current = first as u32 .. last as u32 + 1;
+			},
+			Self::Dense(bits) => bits.next().map(|value| value as u16),
+		}
+	}
+}
+
+/// A set operation, applied a chunk at a time.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Op {
+	Or,
+	And,
+	AndNot,
+}
+
+impl Op {
+	/// Applies the operation to a word of dense chunk storage.
+	fn apply(self, a: u64, b: u64) -> u64 {
+		match self {
+			Self::Or => a | b,
+			Self::And => a & b,
+			Self::AndNot => a & !b,
+		}
+	}
+}
+
+/// Splits a value into its chunk key and its position within the chunk.
+fn split(value: u32) -> (u16, u16) {
+	((value >> 16) as u16, value as u16)
+}
+
+/// Rejoins a chunk key and a position within the chunk.
+fn join(key: u16, low: u16) -> u32 {
+	(key as u32) << 16 | low as u32
+}
+
+/// Finds the run containing a value, or the index of the first run after it.
+fn find_run(runs: &[(u16, u16)], value: u16) -> Result<usize, usize> {
+	runs.binary_search_by(|&(first, last)| {
+		if last < value {
+			Ordering::Less
+		}
+		else if first > value {
+			Ordering::Greater
+		}
+		else {
+			Ordering::Equal
+		}
+	})
+}
+
+/// Adds a value that lies between `runs[idx - 1]` and `runs[idx]`, extending
+/// or joining its neighbors where it touches them.
+fn insert_run(runs: &mut Vec<(u16, u16)>, idx: usize, value: u16) {
+	let after_prev = idx > 0 && runs[idx - 1].1 as u32 + 1 == value as u32;
+	let before_next = idx < runs.len() && runs[idx].0 as u32 == value as u32 + 1;
+	match (after_prev, before_next) {
+		(true, true) => {
+			runs[idx - 1].1 = runs[idx].1;
+			runs.remove(idx);
+		},
+		(true, false) => runs[idx - 1].1 = value,
+		(false, true) => runs[idx].0 = value,
+		(false, false) => runs.insert(idx, (value, value)),
+	}
+}
+
+/// Merges two sorted arrays, removing duplicates.
+fn union(a: &[u16], b: &[u16]) -> Vec<u16> {
+	let mut out = Vec::with_capacity(a.len() + b.len());
+	let (mut i, mut j) = (0, 0);
+	while i < a.len() && j < b.len() {
+		match a[i].cmp(&b[j]) {
+			Ordering::Less => {
+				out.push(a[i]);
+				i += 1;
+			},
+			Ordering::Greater => {
+				out.push(b[j]);
+				j += 1;
+			},
+			Ordering::Equal => {
+				out.push(a[i]);
+				i += 1;
+				j += 1;
+			},
+		}
+	}
+	out.extend_from_slice(&a[i ..]);
+	out.extend_from_slice(&b[j ..]);
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	/// Builds a bit-vector mixing sparse, clustered, and noisy chunks.
+	fn sample(seed: u32) -> BitVec<Msb0, u32> {
+		let mut bits = BitVec::repeat(false, 5 * CHUNK + 123);
+		let mut state = seed;
+		for idx in 0 .. 300 {
+			state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+			bits.set(idx * 1093 + (state >> 24) as usize % 7, true);
+		}
+		bits[CHUNK + 10 .. 2 * CHUNK - 10].set_all(true);
+		bits[2 * CHUNK + 5 .. 2 * CHUNK + 40_000].set_all(true);
+		for idx in (3 * CHUNK .. 4 * CHUNK).step_by(3) {
+			state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+			if state & 0x4000_0000 != 0 {
+				bits.set(idx, true);
+			}
+		}
+		bits.set(5 * CHUNK + 122, true);
+		bits
+	}
+
+	#[test]
+	fn conversion() {
+		let bits = sample(1);
+		let set = CompressedBitmap::from_bitslice(&bits);
+		assert_eq!(set.len(), bits.count_ones() as u64);
+		assert!(set.iter_ones().map(|v| v as usize).eq(bits.iter_ones()));
+		assert_eq!(set.to_bitvec::<Lsb0, u8>(), bits);
+		for value in (0 .. bits.len()).step_by(97) {
+			assert_eq!(set.contains(value as u32), bits[value]);
+			let rank = bits[.. value].count_ones() as u64;
+			assert_eq!(set.rank(value as u32), rank);
+		}
+
+		let kinds = set
+			.chunks
+			.iter()
+			.map(|(_, chunk)| match chunk {
+				Container::Array(_) => 'a',
+				Container::Runs(_) => 'r',
+				Container::Dense(_) => 'd',
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(kinds, ['a', 'r', 'r', 'd', 'a', 'a']);
+	}
+
+	#[test]
+	fn edits() {
+		let bits = sample(2);
+		let mut set = CompressedBitmap::from_bitslice(&bits);
+		let mut model = bits.clone();
+		for idx in 0 .. 3000 {
+			let value = (idx * 7919 % model.len()) as u32;
+			let add = idx % 3 != 0;
+			let old = model[value as usize];
+			model.set(value as usize, add);
+			if add {
+				assert_eq!(set.insert(value), !old);
+			}
+			else {
+				assert_eq!(set.remove(value), old);
+			}
+		}
+		for value in 0 .. 5000 {
+			set.insert(value * 3);
+		}
+		for value in 0 .. 5000 {
+			model.set(value * 3, true);
+		}
+		assert!(set.iter_ones().map(|v| v as usize).eq(model.iter_ones()));
+		let before = set.clone();
+		set.optimize();
+		assert_eq!(set, before);
+		assert_eq!(set.first(), model.first_one().map(|v| v as u32));
+		assert_eq!(set.last(), model.last_one().map(|v| v as u32));
+
+		set.clear();
+		assert!(set.is_empty());
+		assert!(!set.remove(10));
+		assert_eq!(set.rank(u32::MAX), 0);
+		assert!(set.to_bitvec::<Lsb0, usize>().is_empty());
+	}
+
+	#[test]
+	fn set_ops() {
+		let (a, b) = (sample(3), sample(4));
+		let (x, y) = (
+			CompressedBitmap::from_bitslice(&a),
+			CompressedBitmap::from_bitslice(&b),
+		);
+		let check = |set: CompressedBitmap, f: fn(bool, bool) -> bool| {
+			let expected = a
+				.iter()
+				.zip(b.iter())
+				.map(|(l, r)| f(*l, *r))
+				.collect::<BitVec>();
+			let members = set.iter_ones().map(|v| v as usize);
+			assert!(members.eq(expected.iter_ones()));
+		};
+		check(x.union(&y), |l, r| l | r);
+		check(x.intersection(&y), |l, r| l & r);
+		check(x.difference(&y), |l, r| l & !r);
+		check(y.difference(&x), |l, r| r & !l);
+		assert_eq!(x.union_bitslice(&b), x.union(&y));
+		assert_eq!(x.intersection_bitslice(&b), x.intersection(&y));
+		assert_eq!(x.difference_bitslice(&b), x.difference(&y));
+		assert!(x.difference(&x).is_empty());
+
+		let far = [400_000, 1 << 31, u32::MAX].iter().copied().collect();
+		let both = x.union(&far);
+		assert_eq!(both.len(), x.len() + 3);
+		assert_eq!(both.last(), Some(u32::MAX));
+		assert_eq!(both.intersection(&far), far);
+		assert_eq!(both.difference(&far), x);
+		assert_eq!(both.intersection_bitslice(&a), x);
+		assert_eq!(both.difference_bitslice(&a), far);
+		assert_eq!(far.intersection_bitslice(&a), CompressedBitmap::new());
+		assert_eq!(both.difference_bitslice(bits![]), both);
+	}
+}
@@ -0,0 +1,412 @@
+/*! Lock-free slot allocation.
+
+This module provides [`ConcurrentBitmap`], a fixed-size bitmap of slots that can
+be claimed and released from many threads at once through shared references.
+Each slot is one bit in an `AtomicUsize` element: `0` is free and `1` is taken.
+
+Free slots are located with [`BitSlice::first_zero`], which scans a memory
+element at a time, and are claimed with [`BitSlice::compare_exchange_bit`], so
+that two threads can never claim the same slot. A thread that loses a race for
+a slot resumes its scan after that slot.
+
+[`BitSlice::compare_exchange_bit`]: crate::slice::BitSlice::compare_exchange_bit
+[`BitSlice::first_zero`]: crate::slice::BitSlice::first_zero
+[`ConcurrentBitmap`]: self::ConcurrentBitmap
+!*/
+
+use crate::{
+	boxed::BitBox,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	vec::BitVec,
+};
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	ops::Range,
+	sync::atomic::{
+		AtomicUsize,
+		Ordering,
+	},
+};
+
+/** A bitmap of slots that threads can claim and release concurrently.
+
+All methods take `&self`, so a bitmap can be shared between threads through an
+`Arc` or a `static`.
+
+# Type Parameters
+
+- `O`: The ordering of bits within the storage elements.
+
+# Examples
+
+```rust
+use bitvec::concurrent::ConcurrentBitmap;
+
+let slots = ConcurrentBitmap::<bitvec::order::Lsb0>::new(100);
+let a = slots.try_acquire().unwrap();
+let b = slots.try_acquire().unwrap();
+assert_ne!(a, b);
+assert_eq!(slots.count_acquired(), 2);
+
+let run = slots.acquire_n(10).unwrap();
+assert!(slots.as_bitslice()[run .. run + 10].all());
+
+slots.release(a);
+slots.release_n(run, 10);
+assert_eq!(slots.count_acquired(), 1);
+```
+**/
+pub struct ConcurrentBitmap<O = Lsb0>
+where O: BitOrder
+{
+	/// The slots. Set bits are acquired.
+	bits: BitBox<O, AtomicUsize>,
+	/// Where the next [`try_acquire`] call begins its scan.
+	///
+	/// [`try_acquire`]: Self::try_acquire
+	cursor: AtomicUsize,
+}
+
+impl<O> ConcurrentBitmap<O>
+where O: BitOrder
+{
+	/// Constructs a bitmap of `len` free slots.
+	pub fn new(len: usize) -> Self {
+		Self {
+			bits: BitVec::repeat(false, len).into_boxed_bitslice(),
+			cursor: AtomicUsize::new(0),
+		}
+	}
+
+	/// Gets the number of slots.
+	pub fn len(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Tests if the bitmap has no slots.
+	pub fn is_empty(&self) -> bool {
+		self.bits.is_empty()
+	}
+
+	/// Counts the slots that are currently acquired.
+	///
+	/// Other threads may change the bitmap while it is being counted, so this
+	/// is only a snapshot.
+	pub fn count_acquired(&self) -> usize {
+		self.bits.count_ones()
+	}
+
+	/// Tests if a slot is currently acquired.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is out of bounds.
+	pub fn is_acquired(&self, index: usize) -> bool {
+		self.bits[index]
+	}
+
+	/// Claims a free slot.
+	///
+	/// The scan begins where the previous successful call on this bitmap left
+	/// off, so that consecutive calls do not all contend for the lowest free
+	/// slot.
+	///
+	/// # Returns
+	///
+	/// The index of the claimed slot, or `None` if every slot was taken when
+	/// it was scanned.
+	pub fn try_acquire(&self) -> Option<usize> {
+		let start = self.cursor.load(Ordering::Relaxed);
+		let index = self.try_acquire_from(start)?;
+		self.cursor.store(index + 1, Ordering::Relaxed);
+		Some(index)
+	}
+
+	/// Claims a free slot, beginning the scan at a caller-chosen hint.
+	///
+	/// Threads that use different hints, such as `thread_index * len /
+	/// thread_count`, scan different regions of the bitmap first and rarely
+	/// contend with each other.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `hint`: The slot at which to begin scanning. The scan wraps around
+	///   to the start of the bitmap. Hints past the end are reduced modulo the
+	///   length.
+	///
+	/// # Returns
+	///
+	/// The index of the claimed slot, or `None` if every slot was taken when
+	/// it was scanned.
+	pub fn try_acquire_from(&self, hint: usize) -> Option<usize> {
+		let len = self.len();
+		if len == 0 {
+			return None;
+		}
+		let start = hint % len;
+		self.claim_in(start .. len).or_else(|| self.claim_in(0 .. start))
+	}
+
+	/// Claims a run of `n` contiguous free slots.
+	///
+	/// Slots in the run are claimed one at a time. If another thread takes a
+	/// slot in the candidate run first, the slots claimed so far are released
+	/// and the scan continues after the contested slot.
+	///
+	/// # Returns
+	///
+	/// The index of the first slot in the claimed run, or `None` if no run of
+	/// `n` free slots was found.
+	///
+	/// # Panics
+	///
+	/// This panics if `n` is zero.
+	pub fn acquire_n(&self, n: usize) -> Option<usize> {
+		assert!(n != 0, "Cannot acquire an empty run of slots");
+		let bits = self.as_bitslice();
+		let len = bits.len();
+		let mut pos = 0;
+		while pos + n <= len {
+			let start = pos + bits[pos ..].first_zero()?;
+			if start + n > len {
+				return None;
+			}
+			if let Some(taken) = bits[start .. start + n].first_one() {
+				pos = start + taken + 1;
+				continue;
+			}
+			match (0 .. n).find(|&k| !self.claim(start + k)) {
+				None => return Some(start),
+				Some(lost) => {
+					let claimed = start .. start + lost;
+					bits.fetch_and_range(claimed, Ordering::Release);
+					pos = start + lost + 1;
+				},
+			}
+		}
+		None
+	}
+
+	/// Releases an acquired slot.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is out of bounds, or if the slot was not
+	/// acquired.
+	pub fn release(&self, index: usize) {
+		assert!(
+			self.bits.fetch_clear(index, Ordering::Release),
+			"Slot {} released without being acquired",
+			index
+		);
+	}
+
+	/// Releases a run of `n` acquired slots beginning at `start`.
+	///
+	/// # Panics
+	///
+	/// This panics if the run is out of bounds, or if any slot in it was not
+	/// acquired. The run is checked before any slot in it is released, so a
+	/// failed release leaves every slot as it was.
+	pub fn release_n(&self, start: usize, n: usize) {
+		//  Held slots can only be released by their holder, so the run cannot
+		//  change between this check and the release.
+		assert!(
+			self.bits[start .. start + n].all(),
+			"Slots {}..{} released without all being acquired",
+			start,
+			start + n
+		);
+		let held = self
+			.bits
+			.fetch_and_range(start .. start + n, Ordering::Release);
+		debug_assert_eq!(held, n, "Held slots were released concurrently");
+	}
+
+	/// Views the slots as a bit-slice.
+	pub fn as_bitslice(&self) -> &BitSlice<O, AtomicUsize> {
+		&self.bits
+	}
+
+	/// Claims the first free slot in a range.
+	fn claim_in(&self, range: Range<usize>) -> Option<usize> {
+		let bits = &self.bits[range.clone()];
+		let mut pos = 0;
+		while let Some(offset) = bits[pos ..].first_zero() {
+			let index = range.start + pos + offset;
+			if self.claim(index) {
+				return Some(index);
+			}
+			pos += offset + 1;
+		}
+		None
+	}
+
+	/// Attempts to claim one slot.
+	fn claim(&self, index: usize) -> bool {
+		self.bits
+			.compare_exchange_bit(
+				index,
+				false,
+				true,
+				Ordering::Acquire,
+				Ordering::Relaxed,
+			)
+			.is_ok()
+	}
+}
+
+impl<O> Debug for ConcurrentBitmap<O>
+where O: BitOrder
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("ConcurrentBitmap")
+			.field("len", &self.len())
+			.field("acquired", &self.count_acquired())
+			.finish()
+	}
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	use std::{
+		sync::Arc,
+		thread,
+		vec::Vec,
+	};
+
+	/// Spawns `threads` workers that share one bitmap, and a table recording
+	/// which worker holds each slot.
+	fn stress<F>(len: usize, threads: usize, work: F)
+	where F: Fn(&ConcurrentBitmap<Msb0>, &[AtomicUsize], usize)
+			+ Send
+			+ Sync
+			+ 'static
+	{
+		let shared = Arc::new((
+			ConcurrentBitmap::<Msb0>::new(len),
+			(0 .. len).map(|_| AtomicUsize::new(0)).collect::<Vec<_>>(),
+			work,
+		));
+		let handles = (0 .. threads)
+			.map(|id| {
+				let shared = shared.clone();
+				thread::spawn(move || {
+					let (bitmap, owners, work) = &*shared;
+					work(bitmap, owners, id + 1)
+				})
+			})
+			.collect::<Vec<_>>();
+		for handle in handles {
+			handle.join().unwrap();
+		}
+		assert_eq!(shared.0.count_acquired(), 0);
+	}
+
+	/// Records `owner` as the holder of a slot, failing if it is held.
+	fn take(owners: &[AtomicUsize], index: usize, owner: usize) {
+		let prev = owners[index].swap(owner, Ordering::Relaxed);
+		assert_eq!(prev, 0, "Slot {} handed to two owners", index);
+	}
+
+	/// Clears the record of `owner` holding a slot.
+	fn give(owners: &[AtomicUsize], index: usize, owner: usize) {
+		let prev = owners[index].swap(0, Ordering::Relaxed);
+		assert_eq!(prev, owner, "Slot {} lost its owner", index);
+	}
+
+	#[test]
+	fn single_slots() {
+		stress(70, 8, |bitmap, owners, id| {
+			let mut held = Vec::new();
+			for round in 0 .. 2000 {
+				let slot = if round % 2 == 0 {
+					bitmap.try_acquire()
+				}
+				else {
+					bitmap.try_acquire_from(id * 9)
+				};
+				if let Some(slot) = slot {
+					take(owners, slot, id);
+					held.push(slot);
+				}
+				if held.len() > 6 || (slot.is_none() && !held.is_empty()) {
+					let slot = held.remove(round % held.len());
+					give(owners, slot, id);
+					bitmap.release(slot);
+				}
+			}
+			for slot in held {
+				give(owners, slot, id);
+				bitmap.release(slot);
+			}
+		});
+	}
+
+	#[test]
+	fn runs() {
+		stress(130, 6, |bitmap, owners, id| {
+			for round in 0 .. 1000 {
+				let n = 1 + (round + id) % 9;
+				if let Some(start) = bitmap.acquire_n(n) {
+					for slot in start .. start + n {
+						take(owners, slot, id);
+					}
+					for slot in start .. start + n {
+						give(owners, slot, id);
+					}
+					bitmap.release_n(start, n);
+				}
+			}
+		});
+	}
+
+	#[test]
+	fn exhaustion() {
+		let bitmap = ConcurrentBitmap::<Lsb0>::new(10);
+		assert!(bitmap.acquire_n(11).is_none());
+		assert_eq!(bitmap.acquire_n(3), Some(0));
+		bitmap.release(1);
+		assert_eq!(bitmap.acquire_n(2), Some(3));
+		assert_eq!(bitmap.try_acquire_from(25), Some(5));
+		let mut rest = (0 .. 5)
+			.filter_map(|_| bitmap.try_acquire())
+			.collect::<Vec<_>>();
+		rest.sort_unstable();
+		assert_eq!(rest, [1, 6, 7, 8, 9]);
+		assert!(bitmap.try_acquire().is_none());
+		assert!(bitmap.acquire_n(1).is_none());
+		assert!(ConcurrentBitmap::<Lsb0>::new(0).try_acquire().is_none());
+	}
+
+	#[test]
+	#[should_panic]
+	fn double_release() {
+		let bitmap = ConcurrentBitmap::<Lsb0>::new(10);
+		bitmap.release(4);
+	}
+
+	#[test]
+	fn partial_release() {
+		let bitmap = ConcurrentBitmap::<Lsb0>::new(10);
+		assert_eq!(bitmap.acquire_n(3), Some(0));
+		let res = std::panic::catch_unwind(|| bitmap.release_n(1, 4));
+		assert!(res.is_err());
+		assert_eq!(bitmap.as_bitslice()[.. 4], bits![1, 1, 1, 0]);
+		bitmap.release_n(0, 3);
+		assert!(bitmap.as_bitslice().not_any());
+	}
+}
@@ -59,7 +59,10 @@ use crate::{
 		DomainMut,
 	},
 	index::BitMask,
-	mem::BitMemory,
+	mem::{
+		BitMemory,
+		BitSignedMemory,
+	},
 	order::{
 		BitOrder,
 		Lsb0,
@@ -349,6 +352,220 @@ pub trait BitField {
 	where M: BitMemory;
 }
 
+/** Signed transfers through a [`BitField`] region.
+
+This trait extends [`BitField`] with loads that sign-extend the bits of a region
+into a signed integer, and with stores that check that a signed integer fits in
+the region before writing it. It is implemented for every [`BitField`] type that
+can be viewed as a [`BitSlice`], and has no methods to implement.
+
+# Type Parameters
+
+- `O`, `T`: The type parameters of the [`BitSlice`] that the implementor views.
+  These are always inferred from the implementor’s [`AsRef`] implementation.
+
+[`BitField`]: self::BitField
+[`BitSlice`]: crate::slice::BitSlice
+**/
+pub trait BitFieldSigned<O, T>: BitField + AsRef<BitSlice<O, T>>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Loads the bits in the `self` region into a signed local value.
+	///
+	/// The most significant bit of the region, as determined by the element
+	/// ordering, is taken as the sign bit and extended through the remaining
+	/// high bits of the return value.
+	///
+	/// # Target-Specific Behavior
+	///
+	/// **THIS FUNCTION CHANGES BEHAVIOR FOR DIFFERENT TARGETS.**
+	///
+	/// The default implementation of this function calls
+	/// [`.load_signed_le()`] on little-endian byte-ordered CPUs, and
+	/// [`.load_signed_be()`] on big-endian byte-ordered CPUs.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: A read reference to some bits in memory. This slice must be
+	///   trimmed to have a width no more than the [`S::BITS`] width of the type
+	///   being loaded.
+	///
+	/// # Returns
+	///
+	/// A value `S` whose least [`self.len()`] significant bits are filled with
+	/// the bits of `self`, and whose remaining bits are copies of the most
+	/// significant bit of `self`.
+	///
+	/// # Panics
+	///
+	/// This method is encouraged to panic if `self` is empty, or wider than a
+	/// single element `S`.
+	///
+	/// [`S::BITS`]: crate::mem::BitSignedMemory::BITS
+	/// [`self.len()`]: crate::slice::BitSlice::len
+	/// [`.load_signed_be()`]: Self::load_signed_be
+	/// [`.load_signed_le()`]: Self::load_signed_le
+	fn load_signed<S>(&self) -> S
+	where S: BitSignedMemory {
+		#[cfg(target_endian = "little")]
+		return self.load_signed_le::<S>();
+
+		#[cfg(target_endian = "big")]
+		return self.load_signed_be::<S>();
+	}
+
+	/// Stores a signed value from the user into the domain of `self`.
+	///
+	/// # Target-Specific Behavior
+	///
+	/// **THIS FUNCTION CHANGES BEHAVIOR FOR DIFFERENT TARGETS.**
+	///
+	/// The default implementation of this function calls
+	/// [`.store_signed_le()`] on little-endian byte-ordered CPUs, and
+	/// [`.store_signed_be()`] on big-endian byte-ordered CPUs.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`: A write reference to some bits in memory. This slice must
+	///   be trimmed to have a width no more than the [`S::BITS`] width of the
+	///   type being stored.
+	/// - `value`: A value, which must be representable as a two’s-complement
+	///   integer [`self.len()`] bits wide.
+	///
+	/// # Panics
+	///
+	/// This method is encouraged to panic if `self` is empty, wider than a
+	/// single element `S`, or too narrow to hold `value`.
+	///
+	/// [`S::BITS`]: crate::mem::BitSignedMemory::BITS
+	/// [`self.len()`]: crate::slice::BitSlice::len
+	/// [`.store_signed_be()`]: Self::store_signed_be
+	/// [`.store_signed_le()`]: Self::store_signed_le
+	fn store_signed<S>(&mut self, value: S)
+	where S: BitSignedMemory {
+		#[cfg(target_endian = "little")]
+		self.store_signed_le(value);
+
+		#[cfg(target_endian = "big")]
+		self.store_signed_be(value);
+	}
+
+	/// Loads a signed value from `self`, using little-endian element `T`
+	/// ordering.
+	///
+	/// This behaves as [`.load_le()`], then sign-extends the loaded value from
+	/// the width of `self` to the full width of `S`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0xF0u8, 0x0F];
+	/// let bits = data.view_bits::<Lsb0>();
+	/// assert_eq!(bits[4 .. 12].load_signed_le::<i8>(), -1);
+	/// assert_eq!(bits[4 .. 16].load_signed_le::<i16>(), 0xFF);
+	/// assert_eq!(bits[0 .. 12].load_signed_le::<i16>(), -16);
+	/// ```
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	fn load_signed_le<S>(&self) -> S
+	where S: BitSignedMemory {
+		sign_extend(self.load_le::<S::Unsigned>(), self.as_ref().len())
+	}
+
+	/// Loads a signed value from `self`, using big-endian element `T`
+	/// ordering.
+	///
+	/// This behaves as [`.load_be()`], then sign-extends the loaded value from
+	/// the width of `self` to the full width of `S`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x0Fu8, 0xF0];
+	/// let bits = data.view_bits::<Msb0>();
+	/// assert_eq!(bits[4 .. 12].load_signed_be::<i8>(), -1);
+	/// assert_eq!(bits[0 .. 12].load_signed_be::<i16>(), 0xFF);
+	/// assert_eq!(bits[4 .. 16].load_signed_be::<i16>(), -16);
+	/// ```
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	fn load_signed_be<S>(&self) -> S
+	where S: BitSignedMemory {
+		sign_extend(self.load_be::<S::Unsigned>(), self.as_ref().len())
+	}
+
+	/// Stores a signed value into `self`, using little-endian element
+	/// ordering.
+	///
+	/// This checks that `value` fits in the width of `self` as a
+	/// two’s-complement integer, then behaves as [`.store_le()`].
+	///
+	/// # Panics
+	///
+	/// This panics if `value` is outside the range
+	/// `-2.pow(len - 1) .. 2.pow(len - 1)`, where `len` is the width of
+	/// `self`, in addition to the conditions of [`.store_le()`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 2];
+	/// let bits = data.view_bits_mut::<Lsb0>();
+	/// bits[2 .. 14].store_signed_le(-2i16);
+	/// assert_eq!(data, [0xF8, 0x3F]);
+	/// ```
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	fn store_signed_le<S>(&mut self, value: S)
+	where S: BitSignedMemory {
+		self.store_le(check_signed(value, self.as_ref().len()));
+	}
+
+	/// Stores a signed value into `self`, using big-endian element ordering.
+	///
+	/// This checks that `value` fits in the width of `self` as a
+	/// two’s-complement integer, then behaves as [`.store_be()`].
+	///
+	/// # Panics
+	///
+	/// This panics if `value` is outside the range
+	/// `-2.pow(len - 1) .. 2.pow(len - 1)`, where `len` is the width of
+	/// `self`, in addition to the conditions of [`.store_be()`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 2];
+	/// let bits = data.view_bits_mut::<Msb0>();
+	/// bits[2 .. 14].store_signed_be(-2i16);
+	/// assert_eq!(data, [0x3F, 0xF8]);
+	/// ```
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	fn store_signed_be<S>(&mut self, value: S)
+	where S: BitSignedMemory {
+		self.store_be(check_signed(value, self.as_ref().len()));
+	}
+}
+
+impl<F, O, T> BitFieldSigned<O, T> for F
+where
+	F: BitField + AsRef<BitSlice<O, T>> + ?Sized,
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
 impl<T> BitField for BitSlice<Lsb0, T>
 where T: BitStore
 {
@@ -760,6 +977,35 @@ where M: BitMemory {
 	}
 }
 
+/// Sign-extends a loaded value from the width of its source region.
+///
+/// The region width has already been checked by the unsigned load.
+fn sign_extend<S>(value: S::Unsigned, len: usize) -> S
+where S: BitSignedMemory {
+	let shamt = S::BITS as usize - len;
+	S::from_unsigned(value << shamt) >> shamt
+}
+
+/// Asserts that a signed value is representable in a region of `len` bits.
+///
+/// # Returns
+///
+/// The bit-pattern of `value`, ready for an unsigned store.
+///
+/// # Panics
+///
+/// This panics if `len` is not a valid region width for `S`, or if `value`
+/// does not survive truncation to `len` bits followed by sign extension.
+fn check_signed<S>(value: S, len: usize) -> S::Unsigned
+where S: BitSignedMemory {
+	check::<S::Unsigned>("store", len);
+	let shamt = S::BITS as usize - len;
+	if (value << shamt) >> shamt != value {
+		panic!("Cannot store {} into a {}-bit signed region", value, len);
+	}
+	value.into_unsigned()
+}
+
 /** Reads a value out of a section of a memory element.
 
 This function is used to extract a portion of an `M` value from a portion of a
@@ -130,3 +130,37 @@ fn wrappers() {
 	assert_eq!(d.load_le::<u8>(), !0);
 	assert_eq!(d.load_be::<u8>(), !0);
 }
+
+#[test]
+fn signed_fields() {
+	let mut data = [0u16; 3];
+	let bits = data.view_bits_mut::<Msb0>();
+
+	for &value in &[-2048i16, -1, 0, 1, 2047] {
+		bits[5 .. 17].store_signed_le(value);
+		assert_eq!(bits[5 .. 17].load_signed_le::<i16>(), value);
+		assert_eq!(bits[5 .. 17].load_signed_le::<i64>(), value as i64);
+		bits[20 .. 32].store_signed_be(value);
+		assert_eq!(bits[20 .. 32].load_signed_be::<i16>(), value);
+		assert_eq!(bits[20 .. 32].load_signed_be::<i128>(), value as i128);
+	}
+
+	bits[1 .. 9].store_signed_be(i8::MIN);
+	assert_eq!(bits[1 .. 9].load_signed_be::<i8>(), i8::MIN);
+	bits[.. 1].store_signed(-1i8);
+	assert!(bits[0]);
+	assert_eq!(bits[.. 1].load_signed::<i32>(), -1);
+
+	let bits = data.view_bits_mut::<Lsb0>();
+	bits[30 .. 48].store_signed_le(-100_000i32);
+	assert_eq!(bits[30 .. 48].load_signed_le::<i32>(), -100_000);
+	assert_eq!(bits[30 .. 48].load_le::<u32>(), (-100_000i32 as u32) & 0x3FFFF);
+	bits[30 .. 48].store_signed_be(131_071i32);
+	assert_eq!(bits[30 .. 48].load_signed_be::<i32>(), 131_071);
+}
+
+#[test]
+#[should_panic]
+fn signed_overflow() {
+	bits![mut Lsb0, u8; 0; 8][.. 4].store_signed_le(8i8);
+}
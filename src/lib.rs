@@ -275,23 +275,47 @@ pub mod macros;
 
 pub mod access;
 pub mod array;
+pub mod codec;
+pub mod combine;
 mod devel;
 pub mod domain;
 pub mod field;
 pub mod index;
 pub mod mem;
 pub mod order;
+pub mod parse;
 pub mod prelude;
 pub mod ptr;
+pub mod set;
 pub mod slice;
 pub mod store;
+pub mod stream;
 pub mod view;
 
 #[cfg(feature = "alloc")]
 pub mod boxed;
 
+#[cfg(feature = "alloc")]
+pub mod compressed;
+
+#[cfg(feature = "alloc")]
+radium::if_atomic! {
+	if atomic(size) {
+		pub mod concurrent;
+	}
+}
+
+#[cfg(feature = "alloc")]
+pub mod matrix;
+
+#[cfg(feature = "alloc")]
+pub mod packed;
+
+#[cfg(feature = "alloc")]
+pub mod rank;
+
 #[cfg(feature = "alloc")]
 pub mod vec;
 
 #[cfg(feature = "serde")]
-mod serdes;
+pub mod serdes;
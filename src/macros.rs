@@ -234,6 +234,55 @@ macro_rules! bitarr {
 	};
 }
 
+/** Constructs the type name of a [`BitArrayExact`] holding a requested number
+of bits.
+
+Where `bitarr!(for N)` names a [`BitArray`] type whose buffer can hold *at
+least* `N` bits, and which exposes every bit of that buffer, this macro names a
+[`BitArrayExact`] type which exposes *exactly* `N` bits.
+
+The argument syntax is the same as the type constructor syntax of [`bitarr!`]:
+a `for $BITS`, optionally followed by `, in $TYPE` or `, in $ORDER, $TYPE`.
+`$BITS` may be any constant-evaluable `usize` expression. The element count of
+the buffer is computed with [`mem::elts`].
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut bits: BitArr!(for 40, in Msb0, u8) = BitArrayExact::zeroed();
+assert_eq!(bits.len(), 40);
+assert_eq!(bits.as_buffer(), &[0u8; 5]);
+
+bits.set(39, true);
+assert_eq!(bits.last_one(), Some(39));
+```
+
+[`BitArray`]: crate::array::BitArray
+[`BitArrayExact`]: crate::array::BitArrayExact
+[`bitarr!`]: macro@crate::bitarr
+[`mem::elts`]: crate::mem::elts
+**/
+#[macro_export]
+macro_rules! BitArr {
+	(for $len:expr, in $order:ty, $store:ident) => {
+		$crate::array::BitArrayExact::<
+			$order,
+			[$store; $crate::mem::elts::<$store>($len)],
+			[u8; $len],
+		>
+	};
+
+	(for $len:expr, in $store:ident) => {
+		$crate::BitArr!(for $len, in $crate::order::Lsb0, $store)
+	};
+
+	(for $len:expr) => {
+		$crate::BitArr!(for $len, in usize)
+	};
+}
+
 /** Creates a borrowed [`BitSlice`] in the local scope.
 
 This macro constructs a [`BitArray`] temporary and then immediately borrows it
@@ -0,0 +1,1033 @@
+/*! Two-dimensional bit matrices.
+
+This module provides [`BitMatrix`], which stores a `rows × cols` grid of bits in
+a single [`BitBox`] buffer, one row after another. Each row begins `stride` bits
+after the previous one, and by default the stride is rounded up to a whole
+number of memory elements so that every row starts at the front edge of an
+element. Rows are exposed as ordinary [`BitSlice`] regions.
+
+Rectangular regions of a matrix can be borrowed as [`BitMatrixView`] and
+[`BitMatrixViewMut`] values, which share the row accessors of the full matrix.
+
+Matrices also support linear algebra over GF(2): products, elimination, rank,
+nullspace, and inversion.
+
+[`BitBox`]: crate::boxed::BitBox
+[`BitMatrix`]: self::BitMatrix
+[`BitMatrixView`]: self::BitMatrixView
+[`BitMatrixViewMut`]: self::BitMatrixViewMut
+[`BitSlice`]: crate::slice::BitSlice
+!*/
+
+#![cfg(feature = "alloc")]
+
+mod gf2;
+
+use crate::{
+	boxed::BitBox,
+	mem::BitMemory,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	iter::FusedIterator,
+	ops::{
+		Index,
+		Range,
+	},
+};
+
+/** A two-dimensional matrix of bits.
+
+# Type Parameters
+
+- `O`: The ordering of bits within the storage elements.
+- `T`: The storage element type.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::matrix::BitMatrix;
+
+let mut adj = BitMatrix::<Lsb0, u8>::new(3, 4);
+adj.set(0, 1, true);
+adj.set(2, 3, true);
+assert!(adj[(0, 1)]);
+assert_eq!(adj.row(2), bits![0, 0, 0, 1]);
+assert_eq!(adj.column(1).collect::<Vec<_>>(), [true, false, false]);
+
+let t = adj.transpose();
+assert_eq!((t.rows(), t.cols()), (4, 3));
+assert!(t[(3, 2)]);
+```
+**/
+pub struct BitMatrix<O = Lsb0, T = usize>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The row-major buffer, `rows * stride` bits long.
+	bits: BitBox<O, T>,
+	/// The number of rows.
+	rows: usize,
+	/// The number of live bits in each row.
+	cols: usize,
+	/// The distance between the starts of adjacent rows.
+	stride: usize,
+}
+
+impl<O, T> BitMatrix<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Constructs a zeroed matrix.
+	///
+	/// The row stride is `cols` rounded up to a whole number of `T` elements.
+	pub fn new(rows: usize, cols: usize) -> Self {
+		let width = <T::Mem as BitMemory>::BITS as usize;
+		let stride = crate::mem::elts::<T>(cols) * width;
+		Self::with_stride(rows, cols, stride)
+	}
+
+	/// Constructs a zeroed matrix with a chosen row stride.
+	///
+	/// # Parameters
+	///
+	/// - `rows`: The number of rows.
+	/// - `cols`: The number of bits in each row.
+	/// - `stride`: The distance, in bits, between the starts of adjacent rows.
+	///   Strides that are not a multiple of the `T` element width pack the
+	///   matrix more tightly, at the cost of unaligned row access.
+	///
+	/// # Panics
+	///
+	/// This panics if `stride` is less than `cols`.
+	pub fn with_stride(rows: usize, cols: usize, stride: usize) -> Self {
+		assert!(
+			stride >= cols,
+			"Row stride {} is too short for {} columns",
+			stride,
+			cols
+		);
+		Self {
+			bits: BitVec::repeat(false, rows * stride).into_boxed_bitslice(),
+			rows,
+			cols,
+			stride,
+		}
+	}
+
+	/// Gets the number of rows.
+	pub fn rows(&self) -> usize {
+		self.rows
+	}
+
+	/// Gets the number of columns.
+	pub fn cols(&self) -> usize {
+		self.cols
+	}
+
+	/// Gets the distance, in bits, between the starts of adjacent rows.
+	pub fn stride(&self) -> usize {
+		self.stride
+	}
+
+	/// Tests if the matrix has no bits.
+	pub fn is_empty(&self) -> bool {
+		self.rows == 0 || self.cols == 0
+	}
+
+	/// Reads the bit at a row and column, if it exists.
+	pub fn get(&self, row: usize, col: usize) -> Option<bool> {
+		self.as_view().get(row, col)
+	}
+
+	/// Writes the bit at a row and column.
+	///
+	/// # Panics
+	///
+	/// This panics if `row` or `col` is out of bounds.
+	pub fn set(&mut self, row: usize, col: usize, value: bool) {
+		self.as_view_mut().set(row, col, value);
+	}
+
+	/// Views a row.
+	///
+	/// # Panics
+	///
+	/// This panics if `row` is out of bounds.
+	pub fn row(&self, row: usize) -> &BitSlice<O, T> {
+		self.as_view().row(row)
+	}
+
+	/// Mutably views a row.
+	///
+	/// # Panics
+	///
+	/// This panics if `row` is out of bounds.
+	pub fn row_mut(&mut self, row: usize) -> &mut BitSlice<O, T> {
+		let (cols, stride) = (self.cols, self.stride);
+		check_row(row, self.rows);
+		&mut self.bits[row * stride ..][.. cols]
+	}
+
+	/// Iterates over the rows of the matrix.
+	pub fn iter_rows(&self) -> Rows<'_, O, T> {
+		Rows {
+			bits: &self.bits,
+			cols: self.cols,
+			stride: self.stride,
+			front: 0,
+			back: self.rows,
+		}
+	}
+
+	/// Iterates over the bits of one column, from the first row to the last.
+	///
+	/// # Panics
+	///
+	/// This panics if `col` is out of bounds.
+	pub fn column(&self, col: usize) -> Column<'_, O, T> {
+		self.as_view().column(col)
+	}
+
+	/// Produces the transpose of the matrix.
+	///
+	/// The transpose is computed in blocks of 64 rows by 64 columns, each of
+	/// which is loaded into a `[u64; 64]` and transposed with shift and mask
+	/// operations. The rows and columns left over at the bottom and right
+	/// edges are transposed in blocks of eight rows by eight columns, each
+	/// loaded into a single `u64`.
+	pub fn transpose(&self) -> Self {
+		self.as_view().transpose()
+	}
+
+	/// Views the entire matrix.
+	pub fn as_view(&self) -> BitMatrixView<'_, O, T> {
+		BitMatrixView {
+			bits: &self.bits,
+			rows: self.rows,
+			cols: self.cols,
+			stride: self.stride,
+		}
+	}
+
+	/// Mutably views the entire matrix.
+	pub fn as_view_mut(&mut self) -> BitMatrixViewMut<'_, O, T> {
+		BitMatrixViewMut {
+			bits: &mut self.bits,
+			rows: self.rows,
+			cols: self.cols,
+			stride: self.stride,
+		}
+	}
+
+	/// Views a rectangular region of the matrix.
+	///
+	/// # Panics
+	///
+	/// This panics if either range is out of bounds.
+	pub fn submatrix(
+		&self,
+		rows: Range<usize>,
+		cols: Range<usize>,
+	) -> BitMatrixView<'_, O, T>
+	{
+		self.as_view().into_submatrix(rows, cols)
+	}
+
+	/// Mutably views a rectangular region of the matrix.
+	///
+	/// # Panics
+	///
+	/// This panics if either range is out of bounds.
+	pub fn submatrix_mut(
+		&mut self,
+		rows: Range<usize>,
+		cols: Range<usize>,
+	) -> BitMatrixViewMut<'_, O, T>
+	{
+		self.as_view_mut().into_submatrix(rows, cols)
+	}
+
+	/// Views the underlying buffer, including any padding between rows.
+	pub fn as_bitslice(&self) -> &BitSlice<O, T> {
+		&self.bits
+	}
+
+	/// Removes the matrix wrapper, leaving the underlying buffer.
+	pub fn into_bitbox(self) -> BitBox<O, T> {
+		self.bits
+	}
+}
+
+impl<O, T> Clone for BitMatrix<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn clone(&self) -> Self {
+		Self {
+			bits: self.bits.clone(),
+			rows: self.rows,
+			cols: self.cols,
+			stride: self.stride,
+		}
+	}
+}
+
+impl<O, T> Debug for BitMatrix<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		Debug::fmt(&self.as_view(), fmt)
+	}
+}
+
+impl<O, T> Eq for BitMatrix<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+impl<O, T> PartialEq for BitMatrix<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Compares the dimensions and live bits of two matrices. Their strides
+	/// and row padding are not compared.
+	fn eq(&self, other: &Self) -> bool {
+		self.as_view() == other.as_view()
+	}
+}
+
+impl<O, T> Index<(usize, usize)> for BitMatrix<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Output = bool;
+
+	fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+		&self.row(row)[col]
+	}
+}
+
+/** A borrowed rectangular region of a [`BitMatrix`].
+
+[`BitMatrix`]: crate::matrix::BitMatrix
+**/
+pub struct BitMatrixView<'a, O = Lsb0, T = usize>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The parent buffer, from the first bit of the view to its end.
+	bits: &'a BitSlice<O, T>,
+	/// The number of rows.
+	rows: usize,
+	/// The number of live bits in each row.
+	cols: usize,
+	/// The distance between the starts of adjacent rows.
+	stride: usize,
+}
+
+impl<'a, O, T> BitMatrixView<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Gets the number of rows.
+	pub fn rows(&self) -> usize {
+		self.rows
+	}
+
+	/// Gets the number of columns.
+	pub fn cols(&self) -> usize {
+		self.cols
+	}
+
+	/// Reads the bit at a row and column, if it exists.
+	pub fn get(&self, row: usize, col: usize) -> Option<bool> {
+		if row < self.rows && col < self.cols {
+			Some(self.bits[row * self.stride + col])
+		}
+		else {
+			None
+		}
+	}
+
+	/// Views a row.
+	///
+	/// # Panics
+	///
+	/// This panics if `row` is out of bounds.
+	pub fn row(&self, row: usize) -> &'a BitSlice<O, T> {
+		check_row(row, self.rows);
+		&self.bits[row * self.stride ..][.. self.cols]
+	}
+
+	/// Iterates over the rows of the view.
+	pub fn iter_rows(&self) -> Rows<'a, O, T> {
+		Rows {
+			bits: self.bits,
+			cols: self.cols,
+			stride: self.stride,
+			front: 0,
+			back: self.rows,
+		}
+	}
+
+	/// Iterates over the bits of one column, from the first row to the last.
+	///
+	/// # Panics
+	///
+	/// This panics if `col` is out of bounds.
+	pub fn column(&self, col: usize) -> Column<'a, O, T> {
+		assert!(
+			col < self.cols,
+			"Column {} out of bounds for {} columns",
+			col,
+			self.cols
+		);
+		Column {
+			bits: self.bits,
+			col,
+			stride: self.stride,
+			front: 0,
+			back: self.rows,
+		}
+	}
+
+	/// Narrows the view to a rectangular region of itself.
+	///
+	/// # Panics
+	///
+	/// This panics if either range is out of bounds.
+	pub fn into_submatrix(self, rows: Range<usize>, cols: Range<usize>) -> Self {
+		check_ranges(&rows, &cols, self.rows, self.cols);
+		let start = rows.start * self.stride + cols.start;
+		Self {
+			bits: if rows.start == rows.end {
+				BitSlice::empty()
+			}
+			else {
+				&self.bits[start ..]
+			},
+			rows: rows.end - rows.start,
+			cols: cols.end - cols.start,
+			stride: self.stride,
+		}
+	}
+
+	/// Produces the transpose of the view as a new matrix.
+	///
+	/// See [`BitMatrix::transpose`].
+	///
+	/// [`BitMatrix::transpose`]: crate::matrix::BitMatrix::transpose
+	pub fn transpose(&self) -> BitMatrix<O, T> {
+		let mut out = BitMatrix::new(self.cols, self.rows);
+		let (full_rows, full_cols) = (self.rows & !63, self.cols & !63);
+		let mut block = [0u64; 64];
+		for r0 in (0 .. full_rows).step_by(64) {
+			for c0 in (0 .. full_cols).step_by(64) {
+				for (r, word) in block.iter_mut().enumerate() {
+					*word = load64(&self.row(r0 + r)[c0 .. c0 + 64]);
+				}
+				transpose64(&mut block);
+				for (c, &word) in block.iter().enumerate() {
+					store64(&mut out.row_mut(c0 + c)[r0 .. r0 + 64], word);
+				}
+			}
+		}
+
+		for r0 in (0 .. self.rows).step_by(8) {
+			let height = cmp::min(8, self.rows - r0);
+			//  Rows covered by the 64×64 blocks only have their right edge left.
+			let first = if r0 < full_rows { full_cols } else { 0 };
+			for c0 in (first .. self.cols).step_by(8) {
+				let width = cmp::min(8, self.cols - c0);
+				let mut block = 0u64;
+				for r in 0 .. height {
+					let bits = &self.row(r0 + r)[c0 .. c0 + width];
+					block |= (bits.load_ordered() as u64) << (8 * r);
+				}
+				block = transpose8(block);
+				for c in 0 .. width {
+					out.row_mut(c0 + c)[r0 .. r0 + height]
+						.store_ordered((block >> (8 * c)) as usize & 0xFF);
+				}
+			}
+		}
+		out
+	}
+
+	/// Copies the view into a new matrix.
+	pub fn to_matrix(&self) -> BitMatrix<O, T> {
+		let mut out = BitMatrix::new(self.rows, self.cols);
+		for (idx, row) in self.iter_rows().enumerate() {
+			out.row_mut(idx).copy_from_bitslice(row);
+		}
+		out
+	}
+}
+
+impl<O, T> Clone for BitMatrixView<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<O, T> Copy for BitMatrixView<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+impl<O, T> Debug for BitMatrixView<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "BitMatrix<{}×{}> ", self.rows, self.cols)?;
+		let mut list = fmt.debug_list();
+		for row in self.iter_rows() {
+			list.entry(&format_args!("{}", row));
+		}
+		list.finish()
+	}
+}
+
+impl<O, T> Eq for BitMatrixView<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+impl<O, T> PartialEq for BitMatrixView<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn eq(&self, other: &Self) -> bool {
+		self.rows == other.rows
+			&& self.cols == other.cols
+			&& self.iter_rows().zip(other.iter_rows()).all(|(a, b)| a == b)
+	}
+}
+
+impl<O, T> Index<(usize, usize)> for BitMatrixView<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Output = bool;
+
+	fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+		&self.row(row)[col]
+	}
+}
+
+/** A mutably borrowed rectangular region of a [`BitMatrix`].
+
+[`BitMatrix`]: crate::matrix::BitMatrix
+**/
+pub struct BitMatrixViewMut<'a, O = Lsb0, T = usize>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The parent buffer, from the first bit of the view to its end.
+	bits: &'a mut BitSlice<O, T>,
+	/// The number of rows.
+	rows: usize,
+	/// The number of live bits in each row.
+	cols: usize,
+	/// The distance between the starts of adjacent rows.
+	stride: usize,
+}
+
+impl<'a, O, T> BitMatrixViewMut<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Gets the number of rows.
+	pub fn rows(&self) -> usize {
+		self.rows
+	}
+
+	/// Gets the number of columns.
+	pub fn cols(&self) -> usize {
+		self.cols
+	}
+
+	/// Reads the bit at a row and column, if it exists.
+	pub fn get(&self, row: usize, col: usize) -> Option<bool> {
+		self.as_view().get(row, col)
+	}
+
+	/// Writes the bit at a row and column.
+	///
+	/// # Panics
+	///
+	/// This panics if `row` or `col` is out of bounds.
+	pub fn set(&mut self, row: usize, col: usize, value: bool) {
+		self.row_mut(row).set(col, value);
+	}
+
+	/// Views a row.
+	///
+	/// # Panics
+	///
+	/// This panics if `row` is out of bounds.
+	pub fn row(&self, row: usize) -> &BitSlice<O, T> {
+		check_row(row, self.rows);
+		&self.bits[row * self.stride ..][.. self.cols]
+	}
+
+	/// Mutably views a row.
+	///
+	/// # Panics
+	///
+	/// This panics if `row` is out of bounds.
+	pub fn row_mut(&mut self, row: usize) -> &mut BitSlice<O, T> {
+		check_row(row, self.rows);
+		&mut self.bits[row * self.stride ..][.. self.cols]
+	}
+
+	/// Sets every bit in the view to a value.
+	pub fn fill(&mut self, value: bool) {
+		for row in 0 .. self.rows {
+			self.row_mut(row).set_all(value);
+		}
+	}
+
+	/// Reborrows the view as read-only.
+	pub fn as_view(&self) -> BitMatrixView<'_, O, T> {
+		BitMatrixView {
+			bits: self.bits,
+			rows: self.rows,
+			cols: self.cols,
+			stride: self.stride,
+		}
+	}
+
+	/// Narrows the view to a rectangular region of itself.
+	///
+	/// # Panics
+	///
+	/// This panics if either range is out of bounds.
+	pub fn into_submatrix(self, rows: Range<usize>, cols: Range<usize>) -> Self {
+		check_ranges(&rows, &cols, self.rows, self.cols);
+		let start = rows.start * self.stride + cols.start;
+		Self {
+			bits: if rows.start == rows.end {
+				BitSlice::empty_mut()
+			}
+			else {
+				&mut self.bits[start ..]
+			},
+			rows: rows.end - rows.start,
+			cols: cols.end - cols.start,
+			stride: self.stride,
+		}
+	}
+}
+
+impl<O, T> Debug for BitMatrixViewMut<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		Debug::fmt(&self.as_view(), fmt)
+	}
+}
+
+impl<O, T> Index<(usize, usize)> for BitMatrixViewMut<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Output = bool;
+
+	fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+		&self.row(row)[col]
+	}
+}
+
+/** Iterates over the rows of a matrix.
+
+This type is produced by [`BitMatrix::iter_rows`] and
+[`BitMatrixView::iter_rows`].
+
+[`BitMatrix::iter_rows`]: crate::matrix::BitMatrix::iter_rows
+[`BitMatrixView::iter_rows`]: crate::matrix::BitMatrixView::iter_rows
+**/
+#[derive(Clone, Debug)]
+pub struct Rows<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The matrix buffer, from the start of the first row.
+	bits: &'a BitSlice<O, T>,
+	/// The number of live bits in each row.
+	cols: usize,
+	/// The distance between the starts of adjacent rows.
+	stride: usize,
+	/// The index of the next row to yield from the front.
+	front: usize,
+	/// One past the index of the next row to yield from the back.
+	back: usize,
+}
+
+impl<'a, O, T> Rows<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Produces a row by index.
+	fn get(&self, row: usize) -> &'a BitSlice<O, T> {
+		&self.bits[row * self.stride ..][.. self.cols]
+	}
+}
+
+impl<'a, O, T> Iterator for Rows<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Item = &'a BitSlice<O, T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.front += 1;
+		Some(self.get(self.front - 1))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len, Some(len))
+	}
+}
+
+impl<O, T> DoubleEndedIterator for Rows<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.back -= 1;
+		Some(self.get(self.back))
+	}
+}
+
+impl<O, T> ExactSizeIterator for Rows<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+impl<O, T> FusedIterator for Rows<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+/** Iterates over the bits of one column of a matrix.
+
+This type is produced by [`BitMatrix::column`] and [`BitMatrixView::column`].
+
+[`BitMatrix::column`]: crate::matrix::BitMatrix::column
+[`BitMatrixView::column`]: crate::matrix::BitMatrixView::column
+**/
+#[derive(Clone, Debug)]
+pub struct Column<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The matrix buffer, from the start of the first row.
+	bits: &'a BitSlice<O, T>,
+	/// The column being walked.
+	col: usize,
+	/// The distance between the starts of adjacent rows.
+	stride: usize,
+	/// The index of the next row to yield from the front.
+	front: usize,
+	/// One past the index of the next row to yield from the back.
+	back: usize,
+}
+
+impl<O, T> Iterator for Column<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Item = bool;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.front += 1;
+		Some(self.bits[(self.front - 1) * self.stride + self.col])
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len, Some(len))
+	}
+}
+
+impl<O, T> DoubleEndedIterator for Column<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.back -= 1;
+		Some(self.bits[self.back * self.stride + self.col])
+	}
+}
+
+impl<O, T> ExactSizeIterator for Column<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+impl<O, T> FusedIterator for Column<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+/// Asserts that a row index is in bounds.
+fn check_row(row: usize, rows: usize) {
+	assert!(row < rows, "Row {} out of bounds for {} rows", row, rows);
+}
+
+/// Asserts that a pair of ranges select a region within a matrix.
+fn check_ranges(
+	rows: &Range<usize>,
+	cols: &Range<usize>,
+	row_count: usize,
+	col_count: usize,
+)
+{
+	assert!(
+		rows.start <= rows.end && rows.end <= row_count,
+		"Rows {:?} out of bounds for {} rows",
+		rows,
+		row_count
+	);
+	assert!(
+		cols.start <= cols.end && cols.end <= col_count,
+		"Columns {:?} out of bounds for {} columns",
+		cols,
+		col_count
+	);
+}
+
+/** Transposes an 8×8 bit block.
+
+The block is packed with row `r` in byte `r`, and column `c` of that row in bit
+`c` of the byte. Each step exchanges the off-diagonal quadrants of successively
+larger sub-blocks.
+**/
+fn transpose8(mut x: u64) -> u64 {
+	let mut t = (x ^ (x >> 7)) & 0x00AA_00AA_00AA_00AA;
+	x ^= t ^ (t << 7);
+	t = (x ^ (x >> 14)) & 0x0000_CCCC_0000_CCCC;
+	x ^= t ^ (t << 14);
+	t = (x ^ (x >> 28)) & 0x0000_0000_F0F0_F0F0;
+	x ^= t ^ (t << 28);
+	x
+}
+
+/** Transposes a 64×64 bit block.
+
+Row `r` of the block is in element `r`, and column `c` of that row in bit `c`
+of the element. Each step exchanges the off-diagonal quadrants of successively
+smaller sub-blocks.
+**/
+fn transpose64(x: &mut [u64; 64]) {
+	let mut width = 32;
+	let mut mask = 0x0000_0000_FFFF_FFFFu64;
+	while width != 0 {
+		let mut k = 0;
+		while k < 64 {
+			let t = ((x[k] >> width) ^ x[k + width]) & mask;
+			x[k] ^= t << width;
+			x[k + width] ^= t;
+			//  Advance to the next row in the upper half of a sub-block.
+			k = (k + width + 1) & !width;
+		}
+		width >>= 1;
+		mask ^= mask << width;
+	}
+}
+
+/// Loads 64 bits, with bit `i` of the slice in bit `i` of the value.
+///
+/// The bits are loaded in halves, so that this works where `usize` is 32 bits.
+fn load64<O, T>(bits: &BitSlice<O, T>) -> u64
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let (lo, hi) = bits.split_at(32);
+	lo.load_ordered() as u64 | (hi.load_ordered() as u64) << 32
+}
+
+/// Stores 64 bits, taking bit `i` of the slice from bit `i` of the value.
+///
+/// This is the inverse of [`load64`].
+///
+/// [`load64`]: self::load64
+fn store64<O, T>(bits: &mut BitSlice<O, T>, value: u64)
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let (lo, hi) = bits.split_at_mut(32);
+	lo.store_ordered(value as u32 as usize);
+	hi.store_ordered((value >> 32) as usize);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	use alloc::vec::Vec;
+
+	fn pattern<O, T>(rows: usize, cols: usize, stride: usize) -> BitMatrix<O, T>
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let mut out = BitMatrix::with_stride(rows, cols, stride);
+		for r in 0 .. rows {
+			for c in 0 .. cols {
+				out.set(r, c, (r * 7 + c * 3) % 5 < 2);
+			}
+		}
+		out
+	}
+
+	#[test]
+	fn transpose() {
+		assert_eq!(transpose8(1 << 1), 1 << 8);
+		assert_eq!(transpose8(0x0102_0408_1020_4080), 0x0102_0408_1020_4080);
+
+		let mut block = [0u64; 64];
+		block[0] = 1 << 63;
+		block[5] = 1 << 40;
+		transpose64(&mut block);
+		let mut expected = [0u64; 64];
+		expected[63] = 1;
+		expected[40] = 1 << 5;
+		assert_eq!(&block[..], &expected[..]);
+
+		for &(rows, cols, stride) in &[
+			(13, 21, 21),
+			(64, 64, 64),
+			(5, 3, 9),
+			(130, 200, 200),
+			(70, 129, 133),
+		] {
+			let m = pattern::<Msb0, u8>(rows, cols, stride);
+			let t = m.transpose();
+			assert_eq!((t.rows(), t.cols()), (cols, rows));
+			for r in 0 .. rows {
+				for c in 0 .. cols {
+					assert_eq!(m[(r, c)], t[(c, r)]);
+				}
+			}
+			assert_eq!(t.transpose(), m);
+		}
+
+		let m = pattern::<Lsb0, u32>(100, 70, 70);
+		let t = m.transpose();
+		assert!((0 .. 100).all(|r| m.row(r).iter().copied().eq(t.column(r))));
+		assert!(m.row(99).iter().rev().copied().eq(t.column(99).rev()));
+	}
+
+	#[test]
+	fn views() {
+		let mut m = pattern::<Lsb0, u16>(10, 20, 24);
+		assert_eq!(m.stride(), 24);
+		assert_eq!(BitMatrix::<Lsb0, u16>::new(3, 20).stride(), 32);
+		assert_eq!(m.iter_rows().len(), 10);
+		assert_eq!(m.iter_rows().rev().next(), Some(m.row(9)));
+		assert_eq!(m.column(4).collect::<BitVec>().len(), 10);
+
+		let sub = m.submatrix(2 .. 7, 3 .. 11);
+		assert_eq!((sub.rows(), sub.cols()), (5, 8));
+		for r in 0 .. 5 {
+			assert_eq!(sub.row(r), &m.row(r + 2)[3 .. 11]);
+		}
+		assert_eq!(
+			sub.column(0).collect::<Vec<_>>(),
+			m.column(3).skip(2).take(5).collect::<Vec<_>>()
+		);
+		let inner = sub.into_submatrix(1 .. 3, 2 .. 4);
+		assert_eq!(inner[(1, 1)], m[(4, 6)]);
+		assert_eq!(inner.to_matrix().transpose()[(1, 1)], m[(4, 6)]);
+		assert!(m.submatrix(3 .. 3, 0 .. 20).iter_rows().next().is_none());
+
+		let mut sub = m.submatrix_mut(8 .. 10, 18 .. 20);
+		sub.fill(true);
+		sub.set(1, 1, false);
+		assert!(m[(8, 18)] && m[(9, 18)] && !m[(9, 19)]);
+		assert_eq!(m.get(10, 0), None);
+		assert_eq!(m.get(0, 20), None);
+	}
+
+	#[test]
+	#[should_panic]
+	fn row_out_of_bounds() {
+		BitMatrix::<Lsb0, usize>::new(2, 2).row(2);
+	}
+}
@@ -0,0 +1,315 @@
+//! Linear algebra over GF(2).
+//!
+//! Addition in GF(2) is XOR and multiplication is AND, so row operations are
+//! performed a processor word at a time rather than bit by bit.
+
+use super::BitMatrix;
+
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use alloc::vec::Vec;
+
+impl<O, T> BitMatrix<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Constructs an `n × n` identity matrix.
+	pub fn identity(n: usize) -> Self {
+		let mut out = Self::new(n, n);
+		for idx in 0 .. n {
+			out.set(idx, idx, true);
+		}
+		out
+	}
+
+	/// Multiplies the matrix by a column vector.
+	///
+	/// Each bit of the product is the [dot product] of a row with `vector`.
+	///
+	/// # Panics
+	///
+	/// This panics if `vector` is not `self.cols()` bits long.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let mut m = BitMatrix::<Lsb0, u8>::new(2, 3);
+	/// m.row_mut(0).copy_from_bitslice(bits![Lsb0, u8; 1, 1, 0]);
+	/// m.row_mut(1).copy_from_bitslice(bits![Lsb0, u8; 0, 1, 1]);
+	/// assert_eq!(m.mul_vec(bits![1, 1, 1]), bitvec![0, 0]);
+	/// assert_eq!(m.mul_vec(bits![1, 0, 0]), bitvec![1, 0]);
+	/// ```
+	///
+	/// [dot product]: crate::slice::BitSlice::dot
+	pub fn mul_vec<O2, T2>(&self, vector: &BitSlice<O2, T2>) -> BitVec<O, T>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		assert_eq!(
+			vector.len(),
+			self.cols,
+			"Cannot multiply a {}-column matrix by a {}-bit vector",
+			self.cols,
+			vector.len(),
+		);
+		self.iter_rows().map(|row| row.dot(vector)).collect()
+	}
+
+	/// Multiplies the matrix by another matrix.
+	///
+	/// Each row of the product is the XOR of the rows of `rhs` selected by the
+	/// set bits in the corresponding row of `self`.
+	///
+	/// # Panics
+	///
+	/// This panics if `rhs` does not have `self.cols()` rows.
+	pub fn mul_mat<O2, T2>(&self, rhs: &BitMatrix<O2, T2>) -> Self
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		assert_eq!(
+			rhs.rows,
+			self.cols,
+			"Cannot multiply a {}-column matrix by a {}-row matrix",
+			self.cols,
+			rhs.rows,
+		);
+		let mut out = Self::new(self.rows, rhs.cols);
+		for (idx, row) in self.iter_rows().enumerate() {
+			let dst = out.row_mut(idx);
+			for src in row.iter_ones() {
+				dst.xor_ordered(rhs.row(src));
+			}
+		}
+		out
+	}
+
+	/// Transforms the matrix into reduced row echelon form by Gauss-Jordan
+	/// elimination.
+	///
+	/// # Returns
+	///
+	/// The rank of the matrix.
+	pub fn row_reduce(&mut self) -> usize {
+		self.eliminate().len()
+	}
+
+	/// Computes the rank of the matrix.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let mut m = BitMatrix::<Lsb0, usize>::identity(3);
+	/// assert_eq!(m.rank(), 3);
+	/// m.row_mut(2).copy_from_bitslice(bits![1, 1, 0]);
+	/// assert_eq!(m.rank(), 2);
+	/// ```
+	pub fn rank(&self) -> usize {
+		self.clone().row_reduce()
+	}
+
+	/// Computes a basis of the nullspace of the matrix.
+	///
+	/// # Returns
+	///
+	/// A matrix of `self.cols() - self.rank()` rows, each `self.cols()` bits
+	/// long. Every row `x` satisfies `self.mul_vec(x)` being all zeros.
+	pub fn nullspace(&self) -> Self {
+		let mut reduced = self.clone();
+		let pivots = reduced.eliminate();
+		let mut free = BitVec::<O, T>::repeat(true, self.cols);
+		for &col in &pivots {
+			free.set(col, false);
+		}
+
+		let mut out = Self::new(self.cols - pivots.len(), self.cols);
+		for (idx, col) in free.iter_ones().enumerate() {
+			let basis = out.row_mut(idx);
+			basis.set(col, true);
+			for (row, &pivot) in pivots.iter().enumerate() {
+				basis.set(pivot, reduced.bits[row * reduced.stride + col]);
+			}
+		}
+		out
+	}
+
+	/// Computes the inverse of a square matrix.
+	///
+	/// # Returns
+	///
+	/// The inverse, if the matrix is invertible.
+	///
+	/// # Panics
+	///
+	/// This panics if the matrix is not square.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let mut m = BitMatrix::<Lsb0, u16>::identity(3);
+	/// m.set(0, 2, true);
+	/// let inv = m.inverse().unwrap();
+	/// assert_eq!(m.mul_mat(&inv), BitMatrix::identity(3));
+	///
+	/// m.set(2, 0, true);
+	/// assert!(m.inverse().is_none());
+	/// ```
+	pub fn inverse(&self) -> Option<Self> {
+		let n = self.rows;
+		assert_eq!(
+			n, self.cols,
+			"Cannot invert a {}×{} matrix",
+			self.rows, self.cols,
+		);
+		let mut aug = Self::new(n, 2 * n);
+		for (idx, row) in self.iter_rows().enumerate() {
+			let dst = aug.row_mut(idx);
+			dst[.. n].copy_from_bitslice(row);
+			dst.set(n + idx, true);
+		}
+		aug.eliminate();
+		//  The reduced left half is the identity exactly when it has full rank.
+		if (0 .. n).all(|idx| aug[(idx, idx)]) {
+			Some(aug.submatrix(0 .. n, n .. 2 * n).to_matrix())
+		}
+		else {
+			None
+		}
+	}
+
+	/// Performs Gauss-Jordan elimination in place.
+	///
+	/// # Returns
+	///
+	/// The pivot column of each nonzero row, in row order.
+	fn eliminate(&mut self) -> Vec<usize> {
+		let mut pivots = Vec::new();
+		for col in 0 .. self.cols {
+			let rank = pivots.len();
+			let found = (rank .. self.rows)
+				.find(|&row| self.bits[row * self.stride + col]);
+			let pivot = match found {
+				Some(row) => row,
+				None => continue,
+			};
+			self.swap_rows(rank, pivot);
+			//  All bits of the pivot row before `col` are already cleared.
+			let src = self.row(rank)[col ..].to_bitvec();
+			for row in (0 .. self.rows).filter(|&row| row != rank) {
+				if self.bits[row * self.stride + col] {
+					self.row_mut(row)[col ..].xor_ordered(&src);
+				}
+			}
+			pivots.push(col);
+		}
+		pivots
+	}
+
+	/// Exchanges the live bits of two rows.
+	fn swap_rows(&mut self, a: usize, b: usize) {
+		if a == b {
+			return;
+		}
+		let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+		let (cols, stride) = (self.cols, self.stride);
+		let (head, tail) = self.bits.split_at_mut(hi * stride);
+		head[lo * stride ..][.. cols].swap_with_bitslice(&mut tail[.. cols]);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	/// Builds a matrix from a deterministic pseudo-random sequence.
+	fn scramble(rows: usize, cols: usize, seed: u32) -> BitMatrix<Msb0, u32> {
+		let mut state = seed;
+		let mut out = BitMatrix::new(rows, cols);
+		for row in 0 .. rows {
+			for col in 0 .. cols {
+				state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+				out.set(row, col, state & 0x4000_0000 != 0);
+			}
+		}
+		out
+	}
+
+	#[test]
+	fn products() {
+		let a = scramble(37, 70, 1);
+		let b = scramble(70, 45, 2);
+		let ab = a.mul_mat(&b);
+		for r in 0 .. 37 {
+			for c in 0 .. 45 {
+				let expected = a
+					.row(r)
+					.iter()
+					.zip(b.column(c))
+					.fold(false, |acc, (x, y)| acc ^ (*x & y));
+				assert_eq!(ab[(r, c)], expected);
+			}
+		}
+
+		let v = b.column(3).collect::<BitVec>();
+		assert!(a.mul_vec(&v).iter().copied().eq(ab.column(3)));
+		assert_eq!(a.mul_mat(&BitMatrix::<Lsb0, u8>::identity(70)), a);
+	}
+
+	#[test]
+	fn elimination() {
+		let mut m = scramble(20, 30, 3);
+		let (a, b) = (m.row(4).to_bitvec(), m.row(7).to_bitvec());
+		m.row_mut(9).copy_from_bitslice(&a);
+		m.row_mut(9).xor_ordered(&b);
+		let rank = m.rank();
+		assert!(rank <= 19);
+
+		let null = m.nullspace();
+		assert_eq!(null.rows(), 30 - rank);
+		assert_eq!(null.rank(), 30 - rank);
+		for basis in null.iter_rows() {
+			assert!(m.mul_vec(basis).not_any());
+		}
+
+		let mut reduced = m.clone();
+		assert_eq!(reduced.row_reduce(), rank);
+		assert!(reduced.iter_rows().skip(rank).all(BitSlice::not_any));
+	}
+
+	#[test]
+	fn inversion() {
+		let mut found = 0;
+		for seed in 0 .. 20 {
+			let m = scramble(50, 50, seed);
+			match m.inverse() {
+				Some(inv) => {
+					found += 1;
+					assert_eq!(m.rank(), 50);
+					assert_eq!(m.mul_mat(&inv), BitMatrix::identity(50));
+					assert_eq!(inv.mul_mat(&m), BitMatrix::identity(50));
+				},
+				None => assert!(m.rank() < 50),
+			}
+		}
+		assert!(found > 0);
+		assert!(BitMatrix::<Lsb0, u8>::new(0, 0).inverse().is_some());
+	}
+}
@@ -6,6 +6,10 @@ manipulate [`bitvec`] data buffers.
 The [`BitMemory`] trait adds descriptive information to the unsigned integers
 available in the language.
 
+The [`BitSignedMemory`] trait pairs each signed integer with its unsigned
+counterpart, so that signed values can be transferred through the `BitMemory`
+interfaces.
+
 The [`BitRegister`] trait marks the unsigned integers that correspond to
 processor registers, and can therefore be used for buffer control. The integers
 that are `BitMemory` but not `BitRegister` can be composed out of register
@@ -13,12 +17,16 @@ values, but are unable to be used in buffer type parameters.
 
 [`BitMemory`]: self::BitMemory
 [`BitRegister`]: self::BitRegister
+[`BitSignedMemory`]: self::BitSignedMemory
 [`bitvec`]: crate
 !*/
 
 use core::mem;
 
-use funty::IsUnsigned;
+use funty::{
+	IsSigned,
+	IsUnsigned,
+};
 
 use radium::marker::BitOps;
 
@@ -51,6 +59,31 @@ pub trait BitMemory: IsUnsigned + seal::Sealed {
 	const MASK: u8 = Self::BITS - 1;
 }
 
+/** Description of a signed integer memory element.
+
+This trait connects each signed integer to the unsigned integer of the same
+width, which is the type actually moved through the [`BitField`] transfer
+methods. The conversions between the two are bit-pattern reinterpretations, and
+never change the in-memory value.
+
+This trait cannot be implemented outside this crate.
+
+[`BitField`]: crate::field::BitField
+**/
+pub trait BitSignedMemory: IsSigned + seal::Sealed {
+	/// The bit width of the integer.
+	const BITS: u8 = mem::size_of::<Self>() as u8 * 8;
+
+	/// The unsigned integer of the same width.
+	type Unsigned: BitMemory;
+
+	/// Reinterprets an unsigned bit-pattern as a signed value.
+	fn from_unsigned(value: Self::Unsigned) -> Self;
+
+	/// Reinterprets a signed value as an unsigned bit-pattern.
+	fn into_unsigned(self) -> Self::Unsigned;
+}
+
 /** Description of a processor register.
 
 This trait provides information used to describe processor registers. It only
@@ -74,6 +107,34 @@ macro_rules! memory {
 
 memory!(u8, u16, u32, u64, u128, usize);
 
+macro_rules! signed {
+	($($s:ident => $u:ident),+ $(,)?) => { $(
+		impl BitSignedMemory for $s {
+			type Unsigned = $u;
+
+			#[inline(always)]
+			fn from_unsigned(value: $u) -> Self {
+				value as Self
+			}
+
+			#[inline(always)]
+			fn into_unsigned(self) -> $u {
+				self as $u
+			}
+		}
+		impl seal::Sealed for $s {}
+	)+ };
+}
+
+signed!(
+	i8 => u8,
+	i16 => u16,
+	i32 => u32,
+	i64 => u64,
+	i128 => u128,
+	isize => usize,
+);
+
 macro_rules! register {
 	($($t:ident),+ $(,)?) => { $(
 		impl BitRegister for $t {
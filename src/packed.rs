@@ -0,0 +1,540 @@
+/*! Vectors of fixed-width integers.
+
+This module provides [`PackedVec`], which stores a sequence of unsigned integers
+that are each `width` bits wide, back-to-back in a single [`BitVec`]. The width
+is chosen when the vector is created, and can be changed later by re-packing
+every element.
+
+Elements are transferred with [`BitField::load_le`] and [`BitField::store_le`],
+so each element occupies the same bits that a manual
+`bv[i * w .. (i + 1) * w].store_le(v)` would.
+
+Only a runtime width is provided. A variant whose width is a const-generic
+parameter requires const generics, which are not available in the minimum
+supported compiler.
+
+[`BitField::load_le`]: crate::field::BitField::load_le
+[`BitField::store_le`]: crate::field::BitField::store_le
+[`BitVec`]: crate::vec::BitVec
+[`PackedVec`]: self::PackedVec
+!*/
+
+use crate::{
+	field::BitField,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	iter::FusedIterator,
+};
+
+/** A vector of unsigned integers, each stored in a fixed number of bits.
+
+# Type Parameters
+
+- `O`: The ordering of bits within the storage elements.
+- `T`: The storage element type.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::packed::PackedVec;
+
+let mut pv = PackedVec::<Lsb0, u8>::new(5);
+pv.push(17);
+pv.push(31);
+pv.push(4);
+assert_eq!(pv.len(), 3);
+assert_eq!(pv.as_bitslice().len(), 15);
+assert_eq!(pv.get(1), Some(31));
+
+pv.set(1, 9);
+assert_eq!(pv.iter().collect::<Vec<_>>(), [17, 9, 4]);
+assert_eq!(pv.pop(), Some(4));
+```
+**/
+pub struct PackedVec<O = Lsb0, T = usize>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// The packed elements.
+	bits: BitVec<O, T>,
+	/// The number of bits in each element.
+	width: u8,
+}
+
+impl<O, T> PackedVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Constructs an empty vector of `width`-bit elements.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is not in `1 ..= 64`.
+	pub fn new(width: u8) -> Self {
+		Self::with_capacity(width, 0)
+	}
+
+	/// Constructs an empty vector of `width`-bit elements, with room for at
+	/// least `capacity` elements.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is not in `1 ..= 64`.
+	pub fn with_capacity(width: u8, capacity: usize) -> Self {
+		check_width(width);
+		Self {
+			bits: BitVec::with_capacity(capacity * width as usize),
+			width,
+		}
+	}
+
+	/// Gets the number of bits in each element.
+	pub fn width(&self) -> u8 {
+		self.width
+	}
+
+	/// Gets the number of elements in the vector.
+	pub fn len(&self) -> usize {
+		self.bits.len() / self.width as usize
+	}
+
+	/// Tests if the vector is empty.
+	pub fn is_empty(&self) -> bool {
+		self.bits.is_empty()
+	}
+
+	/// Gets the number of elements the vector can hold without reallocating.
+	pub fn capacity(&self) -> usize {
+		self.bits.capacity() / self.width as usize
+	}
+
+	/// Reads an element, if it exists.
+	pub fn get(&self, index: usize) -> Option<u64> {
+		if index < self.len() {
+			Some(self.slot(index).load_le())
+		}
+		else {
+			None
+		}
+	}
+
+	/// Writes an element.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is out of bounds, or if `value` does not fit in
+	/// the element width.
+	pub fn set(&mut self, index: usize, value: u64) {
+		let len = self.len();
+		assert!(
+			index < len,
+			"Index {} out of bounds for {} elements",
+			index,
+			len
+		);
+		self.check_value(value);
+		let width = self.width as usize;
+		self.bits[index * width ..][.. width].store_le(value);
+	}
+
+	/// Appends an element.
+	///
+	/// # Panics
+	///
+	/// This panics if `value` does not fit in the element width.
+	pub fn push(&mut self, value: u64) {
+		self.check_value(value);
+		let start = self.bits.len();
+		self.bits.resize(start + self.width as usize, false);
+		self.bits[start ..].store_le(value);
+	}
+
+	/// Removes the last element and returns it, if the vector is not empty.
+	pub fn pop(&mut self) -> Option<u64> {
+		let len = self.len().checked_sub(1)?;
+		let value = self.slot(len).load_le();
+		self.bits.truncate(len * self.width as usize);
+		Some(value)
+	}
+
+	/// Resizes the vector to `new_len` elements, filling any new elements with
+	/// `value`.
+	///
+	/// # Panics
+	///
+	/// This panics if `value` does not fit in the element width.
+	pub fn resize(&mut self, new_len: usize, value: u64) {
+		self.check_value(value);
+		let len = self.len();
+		if new_len <= len {
+			self.bits.truncate(new_len * self.width as usize);
+			return;
+		}
+		self.bits.reserve((new_len - len) * self.width as usize);
+		for _ in len .. new_len {
+			self.push(value);
+		}
+	}
+
+	/// Shortens the vector to at most `len` elements.
+	pub fn truncate(&mut self, len: usize) {
+		if len < self.len() {
+			self.bits.truncate(len * self.width as usize);
+		}
+	}
+
+	/// Removes all elements.
+	pub fn clear(&mut self) {
+		self.bits.clear();
+	}
+
+	/// Re-packs every element into a new width.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is not in `1 ..= 64`, or if any element does not
+	/// fit in the new width. The vector is unchanged if this panics.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::packed::PackedVec;
+	///
+	/// let mut pv = PackedVec::<Msb0, u16>::new(12);
+	/// pv.extend(vec![100, 4000, 7]);
+	/// pv.repack(17);
+	/// assert_eq!(pv.width(), 17);
+	/// pv.push(100_000);
+	/// assert_eq!(pv.iter().collect::<Vec<_>>(), [100, 4000, 7, 100_000]);
+	/// ```
+	pub fn repack(&mut self, width: u8) {
+		check_width(width);
+		let mut out = Self::with_capacity(width, self.len());
+		for value in self.iter() {
+			out.push(value);
+		}
+		*self = out;
+	}
+
+	/// Iterates over the elements.
+	pub fn iter(&self) -> Iter<'_, O, T> {
+		Iter {
+			vec: self,
+			front: 0,
+			back: self.len(),
+		}
+	}
+
+	/// Views the packed elements as a bit-slice.
+	pub fn as_bitslice(&self) -> &BitSlice<O, T> {
+		&self.bits
+	}
+
+	/// Removes the vector wrapper, leaving the packed elements.
+	pub fn into_bitvec(self) -> BitVec<O, T> {
+		self.bits
+	}
+
+	/// Views the bits of an in-bounds element.
+	fn slot(&self, index: usize) -> &BitSlice<O, T> {
+		let width = self.width as usize;
+		&self.bits[index * width ..][.. width]
+	}
+
+	/// Views the bits of a range of in-bounds elements.
+	fn slot_range(&self, start: usize, end: usize) -> &BitSlice<O, T> {
+		let width = self.width as usize;
+		&self.bits[start * width .. end * width]
+	}
+
+	/// Asserts that a value fits in the element width.
+	fn check_value(&self, value: u64) {
+		assert!(
+			self.width == 64 || value >> self.width == 0,
+			"Value {} does not fit in {} bits",
+			value,
+			self.width
+		);
+	}
+}
+
+impl<O, T> Clone for PackedVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	fn clone(&self) -> Self {
+		Self {
+			bits: self.bits.clone(),
+			width: self.width,
+		}
+	}
+}
+
+impl<O, T> Debug for PackedVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "PackedVec<u{}> ", self.width)?;
+		fmt.debug_list().entries(self.iter()).finish()
+	}
+}
+
+impl<O, T> Eq for PackedVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+}
+
+impl<O, T> PartialEq for PackedVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Compares the element sequences of two vectors. Vectors of different
+	/// widths are equal if they hold the same values.
+	fn eq(&self, other: &Self) -> bool {
+		self.len() == other.len() && self.iter().eq(other.iter())
+	}
+}
+
+impl<O, T> Extend<u64> for PackedVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = u64> {
+		let iter = iter.into_iter();
+		self.bits.reserve(iter.size_hint().0 * self.width as usize);
+		for value in iter {
+			self.push(value);
+		}
+	}
+}
+
+impl<'a, O, T> IntoIterator for &'a PackedVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	type IntoIter = Iter<'a, O, T>;
+	type Item = u64;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/** Iterates over the elements of a [`PackedVec`].
+
+This type is produced by [`PackedVec::iter`].
+
+[`PackedVec`]: crate::packed::PackedVec
+[`PackedVec::iter`]: crate::packed::PackedVec::iter
+**/
+pub struct Iter<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// The vector being iterated.
+	vec: &'a PackedVec<O, T>,
+	/// The index of the next element to yield from the front.
+	front: usize,
+	/// One past the index of the next element to yield from the back.
+	back: usize,
+}
+
+impl<O, T> Clone for Iter<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	fn clone(&self) -> Self {
+		Self {
+			vec: self.vec,
+			front: self.front,
+			back: self.back,
+		}
+	}
+}
+
+impl<O, T> Debug for Iter<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_tuple("Iter")
+			.field(&self.vec.slot_range(self.front, self.back))
+			.finish()
+	}
+}
+
+impl<O, T> Iterator for Iter<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	type Item = u64;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.front += 1;
+		Some(self.vec.slot(self.front - 1).load_le())
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len, Some(len))
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.front = core::cmp::min(self.front.saturating_add(n), self.back);
+		self.next()
+	}
+}
+
+impl<O, T> DoubleEndedIterator for Iter<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.back -= 1;
+		Some(self.vec.slot(self.back).load_le())
+	}
+}
+
+impl<O, T> ExactSizeIterator for Iter<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+}
+
+impl<O, T> FusedIterator for Iter<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+}
+
+/// Asserts that an element width is supported.
+fn check_width(width: u8) {
+	assert!(
+		(1 ..= 64).contains(&width),
+		"Element width {} is not in 1 ..= 64",
+		width
+	);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	use alloc::vec::Vec;
+
+	#[test]
+	fn packed_vec() {
+		for &width in &[5u8, 12, 17, 64] {
+			let mask = if width == 64 { !0 } else { (1u64 << width) - 1 };
+			let values = (0 .. 100u64)
+				.map(|n| n.wrapping_mul(0x9E37_79B9_7F4A_7C15) & mask)
+				.collect::<Vec<_>>();
+
+			let mut pv = PackedVec::<Msb0, u8>::new(width);
+			pv.extend(values.iter().copied());
+			assert_eq!(pv.len(), 100);
+			assert_eq!(pv.as_bitslice().len(), 100 * width as usize);
+			assert!(pv.iter().eq(values.iter().copied()));
+			assert!(pv.iter().rev().eq(values.iter().rev().copied()));
+			assert_eq!(pv.iter().nth(42), Some(values[42]));
+			assert_eq!(
+				pv.as_bitslice()[42 * width as usize ..][.. width as usize]
+					.load_le::<u64>(),
+				values[42]
+			);
+
+			pv.set(7, mask);
+			assert_eq!(pv.get(7), Some(mask));
+			assert_eq!(pv.get(6), Some(values[6]));
+			assert_eq!(pv.get(100), None);
+			assert_eq!(pv.pop(), Some(values[99]));
+
+			pv.resize(120, 3);
+			assert_eq!(pv.len(), 120);
+			assert_eq!(pv.get(119), Some(3));
+			pv.resize(10, 0);
+			assert_eq!(pv.len(), 10);
+		}
+
+		let mut pv = PackedVec::<Lsb0, u32>::new(17);
+		pv.extend(vec![1, 100_000, 9]);
+		let copy = pv.clone();
+		pv.repack(40);
+		assert_eq!(pv, copy);
+		pv.repack(17);
+		assert_eq!(pv.width(), 17);
+		assert!(pv.iter().eq(vec![1, 100_000, 9]));
+		pv.clear();
+		assert!(pv.is_empty());
+		assert_eq!(pv.pop(), None);
+	}
+
+	#[test]
+	#[should_panic]
+	fn value_too_wide() {
+		PackedVec::<Lsb0, usize>::new(5).push(32);
+	}
+
+	#[test]
+	#[should_panic]
+	fn repack_too_narrow() {
+		let mut pv = PackedVec::<Lsb0, usize>::new(12);
+		pv.push(4000);
+		pv.repack(8);
+	}
+}
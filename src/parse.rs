@@ -0,0 +1,477 @@
+/*! Parsing bit-sequences from text.
+
+[`BitArray`], [`BitBox`], and [`BitVec`] implement [`FromStr`] and
+`TryFrom<&str>` with the grammar described here. The parser accepts the text
+that the formatting traits produce, as well as hand-written literals:
+
+- `10110010`: binary digits, one per bit, in index order.
+- `0b1011_0010`: binary digits with a radix prefix. Underscores may separate
+  digits in any prefixed or unprefixed word.
+- `0xB2`: hexadecimal digits, four bits per digit, in either case. Words
+  without a prefix are always binary, so hexadecimal words must have one.
+- `[10110010, 0b110]`: a bracketed, comma-separated list of words, as written
+  by the [`Display`] and [`Binary`] implementations, and by the alternate
+  (`{:#x}`) forms of [`LowerHex`] and [`UpperHex`]. The words are
+  concatenated. The text may also begin with the `Debug` header that
+  precedes the list, such as `BitVec<Msb0, u8> { … } [10110010]`. The header
+  must have this shape, a `Bit…` type name with its parameters followed by a
+  braced block, but its contents are ignored.
+
+Surrounding whitespace is ignored, both around the text and around the words in
+a list.
+
+# Hexadecimal Digits
+
+Each hexadecimal digit expands to four bits, with the digit’s most significant
+bit first in index order. This is the same mapping that the [`LowerHex`] and
+[`UpperHex`] formatters use, so the memory that a hexadecimal literal produces
+depends on the bit-order: `0xB2` parses into the byte `0xB2` under [`Msb0`],
+and into `0x4D` under [`Lsb0`], whose own hexadecimal rendering is again `b2`.
+
+The formatters render a partially-filled memory element with fewer digits, and
+its leading digit may stand for fewer than four bits. Hexadecimal words cannot
+record this, so only binary text round-trips exactly.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let bv: BitArray<Msb0, u8> = "0b1011_0010".parse().unwrap();
+assert_eq!(bv.as_slice(), &[0xB2]);
+
+# #[cfg(feature = "alloc")] {
+let bv: BitVec<Lsb0, u8> = "0xB2".parse().unwrap();
+assert_eq!(bv.as_slice(), &[0x4D]);
+assert_eq!(format!("{:x}", bv), "[b2]");
+
+let text = format!("{:?}", bits![Msb0, u16; 1, 0, 1]);
+let bb: BitBox<Msb0, u16> = text.parse().unwrap();
+assert_eq!(bb, bits![1, 0, 1]);
+
+let err = "10x1".parse::<BitVec>().unwrap_err();
+assert_eq!(err.index(), Some(2));
+# }
+```
+
+[`BitArray`]: crate::array::BitArray
+[`BitBox`]: crate::boxed::BitBox
+[`BitVec`]: crate::vec::BitVec
+[`Binary`]: core::fmt::Binary
+[`Display`]: core::fmt::Display
+[`FromStr`]: core::str::FromStr
+[`LowerHex`]: core::fmt::LowerHex
+[`Lsb0`]: crate::order::Lsb0
+[`Msb0`]: crate::order::Msb0
+[`UpperHex`]: core::fmt::UpperHex
+!*/
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
+/** The error type returned when text cannot be parsed as a bit-sequence.
+
+Positions are byte offsets into the parsed text.
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ParseBitsError {
+	/// A character that is not allowed where it appears.
+	InvalidChar {
+		/// The byte offset of the character.
+		index: usize,
+		/// The character.
+		found: char,
+	},
+	/// A bracketed list that is not closed.
+	Unclosed {
+		/// The byte offset of the opening bracket.
+		index: usize,
+	},
+	/// The text holds a different number of bits than the fixed-size
+	/// destination.
+	Length {
+		/// The number of bits in the destination.
+		expected: usize,
+		/// The number of bits in the text.
+		found: usize,
+	},
+}
+
+impl ParseBitsError {
+	/// Gets the byte offset in the text at which parsing failed.
+	///
+	/// # Returns
+	///
+	/// The position of the offending character or bracket, or `None` for a
+	/// length mismatch, which is not caused by any one position.
+	pub fn index(&self) -> Option<usize> {
+		match *self {
+			Self::InvalidChar { index, .. } | Self::Unclosed { index } => {
+				Some(index)
+			},
+			Self::Length { .. } => None,
+		}
+	}
+
+	/// Checks the number of parsed bits against a fixed-size destination.
+	pub(crate) fn check_len(expected: usize, found: usize) -> Result<(), Self> {
+		if expected == found {
+			Ok(())
+		}
+		else {
+			Err(Self::Length { expected, found })
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for ParseBitsError {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::InvalidChar { index, found } => write!(
+				fmt,
+				"Invalid character {:?} in bit-sequence text at byte {}",
+				found, index
+			),
+			Self::Unclosed { index } => write!(
+				fmt,
+				"The list opened at byte {} is not closed",
+				index
+			),
+			Self::Length { expected, found } => write!(
+				fmt,
+				"Expected {} bits of text, but found {}",
+				expected, found
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseBitsError {
+}
+
+/** Walks the bits described by a text.
+
+# Parameters
+
+- `text`: The text to parse, in the grammar described in the module
+  documentation.
+- `push`: Receives each parsed bit, in index order.
+
+# Returns
+
+An error describing the first part of `text` that does not fit the grammar. The
+bits before that point have already been given to `push`.
+**/
+pub(crate) fn parse<F>(text: &str, mut push: F) -> Result<(), ParseBitsError>
+where F: FnMut(bool) {
+	let (start, body) = trim(text, 0);
+	if !body.ends_with(']') {
+		let open = match body.rfind('[') {
+			Some(open) => open,
+			None => return word(body, start, &mut push),
+		};
+		//  A closed list may only be followed by whitespace.
+		let after = body[open ..].find(']').map(|close| open + close + 1);
+		if let Some(after) = after {
+			let (index, rest) = trim(&body[after ..], start + after);
+			if let Some(found) = rest.chars().next() {
+				return Err(ParseBitsError::InvalidChar { index, found });
+			}
+		}
+		//  The bracket only opens a list at the start of the text, or after a
+		//  `Debug` header. Anywhere else, it is the first error unless the
+		//  text before it has one.
+		let prefix = body[.. open].trim_end();
+		if prefix.is_empty() || is_header(prefix) {
+			return Err(ParseBitsError::Unclosed {
+				index: start + open,
+			});
+		}
+		if prefix.ends_with(']') {
+			list(prefix, start, &mut push)?;
+		}
+		else if !prefix.contains('[') {
+			word(prefix, start, &mut push)?;
+		}
+		return Err(ParseBitsError::InvalidChar {
+			index: start + open,
+			found: '[',
+		});
+	}
+	list(body, start, &mut push)
+}
+
+/** Walks the bits of a bracketed list, with an optional `Debug` header.
+
+# Parameters
+
+- `body`: The trimmed text, which ends with `]`.
+- `start`: The index of `body` in the original text.
+- `push`: Receives each parsed bit, in index order.
+**/
+fn list<F>(body: &str, start: usize, push: &mut F) -> Result<(), ParseBitsError>
+where F: FnMut(bool) {
+	//  `Debug` headers never contain `]`, but may name array types with `[`.
+	let open = match body.rfind('[') {
+		Some(open) => open,
+		None => {
+			return Err(ParseBitsError::InvalidChar {
+				index: start + body.len() - 1,
+				found: ']',
+			});
+		},
+	};
+	let header = body[.. open].trim_end();
+	if let Some(found) = header.chars().next() {
+		if !is_header(header) {
+			return Err(ParseBitsError::InvalidChar {
+				index: start,
+				found,
+			});
+		}
+	}
+
+	let list = &body[open + 1 .. body.len() - 1];
+	let base = start + open + 1;
+	let mut from = 0;
+	loop {
+		let comma = list[from ..].find(',').map(|len| from + len);
+		let item = &list[from .. comma.unwrap_or(list.len())];
+		let (at, item) = trim(item, base + from);
+		match comma {
+			//  Only the last item may be empty, after a trailing comma.
+			Some(comma) if item.is_empty() => {
+				return Err(ParseBitsError::InvalidChar {
+					index: base + comma,
+					found: ',',
+				});
+			},
+			Some(comma) => {
+				word(item, at, push)?;
+				from = comma + 1;
+			},
+			None => return word(item, at, push),
+		}
+	}
+}
+
+/// Tests whether text has the shape of a `Debug` header, `Bit…<…> {…}`: a
+/// type name beginning with `Bit`, non-empty type parameters, and a braced
+/// block.
+fn is_header(text: &str) -> bool {
+	let brace = match text.find('{') {
+		Some(brace) => brace,
+		None => return false,
+	};
+	let name = text[.. brace].trim_end();
+	let (ty, params) = match name.find('<') {
+		Some(lt) => (&name[.. lt], &name[lt + 1 ..]),
+		None => return false,
+	};
+	ty.len() > 3
+		&& ty.starts_with("Bit")
+		&& ty.chars().all(|c| c.is_ascii_alphanumeric())
+		&& params.ends_with('>')
+		&& !params[.. params.len() - 1].trim().is_empty()
+		&& text.ends_with('}')
+}
+
+/// Strips surrounding whitespace, and reports where the remainder begins.
+fn trim(text: &str, offset: usize) -> (usize, &str) {
+	let rest = text.trim_start();
+	(offset + text.len() - rest.len(), rest.trim_end())
+}
+
+/// Parses one word of binary or hexadecimal digits.
+fn word<F>(
+	text: &str,
+	offset: usize,
+	push: &mut F,
+) -> Result<(), ParseBitsError>
+where
+	F: FnMut(bool),
+{
+	let (digits, hex) = match text.get(.. 2) {
+		Some("0b") | Some("0B") => (2, false),
+		Some("0x") | Some("0X") => (2, true),
+		_ => (0, false),
+	};
+	for (idx, ch) in text[digits ..].char_indices() {
+		let value = match (ch, hex) {
+			('_', _) => continue,
+			('0', false) => Some(0),
+			('1', false) => Some(1),
+			(_, true) => ch.to_digit(16),
+			_ => None,
+		}
+		.ok_or(ParseBitsError::InvalidChar {
+			index: offset + digits + idx,
+			found: ch,
+		})?;
+		if hex {
+			for shift in (0 .. 4).rev() {
+				push(value >> shift & 1 != 0);
+			}
+		}
+		else {
+			push(value != 0);
+		}
+	}
+	Ok(())
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	use core::{
+		convert::TryFrom,
+		str::FromStr,
+	};
+
+	#[test]
+	fn words() {
+		let expected = bits![Msb0, u8; 1, 0, 1, 1, 0, 0, 1, 0];
+		for text in &[
+			"10110010",
+			"0b1011_0010",
+			"  0B10110010\n",
+			"0xB2",
+			"0xb_2",
+			"[10110010]",
+			"[1011, 0b0010, ]",
+			"[0xb, 0b0010]",
+		] {
+			let bv = BitVec::<Msb0, u8>::from_str(text).unwrap();
+			assert_eq!(bv, expected, "{}", text);
+		}
+
+		assert!(BitVec::<Lsb0, u32>::from_str("").unwrap().is_empty());
+		assert!(BitVec::<Lsb0, u32>::from_str("[]").unwrap().is_empty());
+		assert!(BitVec::<Lsb0, u32>::from_str("0x").unwrap().is_empty());
+
+		let bv = BitVec::<Lsb0, u8>::try_from("0xB2").unwrap();
+		assert_eq!(bv.as_slice(), &[0x4D]);
+	}
+
+	#[test]
+	fn round_trip() {
+		let bits = bits![Msb0, u16; 0, 1, 1, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 1,
+			1, 0, 1, 0, 1];
+		let bv = bits.to_bitvec();
+		for text in &[
+			format!("{}", bits),
+			format!("{:b}", bits),
+			format!("{:#b}", bits),
+			format!("{:?}", bits),
+			format!("{:#?}", bits),
+			format!("{:?}", bv),
+			format!("{:#?}", bv),
+		] {
+			assert_eq!(text.parse::<BitVec<Msb0, u16>>().unwrap(), bits);
+			assert_eq!(text.parse::<BitBox<Lsb0, u8>>().unwrap(), bits);
+		}
+
+		let ba = bitarr![Lsb0, u8; 1, 1, 0, 1];
+		let text = format!("{:?}", ba);
+		assert_eq!(text.parse::<BitArray<Lsb0, u8>>().unwrap(), ba);
+		let text = format!("{:#X}", ba);
+		assert_eq!(text.parse::<BitArray<Lsb0, u8>>().unwrap(), ba);
+		let ba = bitarr![Msb0, u8; 1; 16];
+		let text = format!("{:#?}", ba);
+		assert_eq!(text.parse::<BitArray<Msb0, [u8; 2]>>().unwrap(), ba);
+	}
+
+	#[test]
+	fn errors() {
+		let err = |text: &str| BitVec::<Msb0, u8>::from_str(text).unwrap_err();
+
+		assert_eq!(err("1012"), ParseBitsError::InvalidChar {
+			index: 3,
+			found: '2',
+		});
+		assert_eq!(err("0b1x"), ParseBitsError::InvalidChar {
+			index: 3,
+			found: 'x',
+		});
+		assert_eq!(err("0xAG"), ParseBitsError::InvalidChar {
+			index: 3,
+			found: 'G',
+		});
+		assert_eq!(err(" [10, 1 1]"), ParseBitsError::InvalidChar {
+			index: 7,
+			found: ' ',
+		});
+		assert_eq!(err("[10,, 1]"), ParseBitsError::InvalidChar {
+			index: 4,
+			found: ',',
+		});
+		assert_eq!(err("[10, 1"), ParseBitsError::Unclosed { index: 0 });
+		assert_eq!(err("10]"), ParseBitsError::InvalidChar {
+			index: 2,
+			found: ']',
+		});
+		assert_eq!(err("xy [10]"), ParseBitsError::InvalidChar {
+			index: 0,
+			found: 'x',
+		});
+		assert_eq!(err("junk} [10]"), ParseBitsError::InvalidChar {
+			index: 0,
+			found: 'j',
+		});
+		assert_eq!(err("BitVec { } [10]"), ParseBitsError::InvalidChar {
+			index: 0,
+			found: 'B',
+		});
+		assert_eq!(err("[10] x"), ParseBitsError::InvalidChar {
+			index: 5,
+			found: 'x',
+		});
+		assert_eq!(err("[10] [1"), ParseBitsError::InvalidChar {
+			index: 5,
+			found: '[',
+		});
+		assert_eq!(err("10[1"), ParseBitsError::InvalidChar {
+			index: 2,
+			found: '[',
+		});
+		assert_eq!(err("1x [1"), ParseBitsError::InvalidChar {
+			index: 1,
+			found: 'x',
+		});
+		assert_eq!(
+			err("BitVec<Msb0, u8> { x } [1"),
+			ParseBitsError::Unclosed { index: 23 },
+		);
+		assert_eq!(err("Bit<>{} [10]"), ParseBitsError::InvalidChar {
+			index: 0,
+			found: 'B',
+		});
+		assert_eq!(err("BitVec<> {} [10]"), ParseBitsError::InvalidChar {
+			index: 0,
+			found: 'B',
+		});
+		assert_eq!(err("10x1").index(), Some(2));
+
+		assert_eq!(
+			BitArray::<Msb0, u8>::from_str("0xB").unwrap_err(),
+			ParseBitsError::Length {
+				expected: 8,
+				found: 4,
+			},
+		);
+		assert_eq!(
+			BitArray::<Msb0, u8>::from_str("0x1_FF").unwrap_err(),
+			ParseBitsError::Length {
+				expected: 8,
+				found: 12,
+			},
+		);
+	}
+}
@@ -7,10 +7,17 @@ This module collects the general public API into a single spot for inclusion, as
 !*/
 
 pub use crate::{
-	array::BitArray,
+	array::{
+		BitArray,
+		BitArrayExact,
+	},
 	bitarr,
+	BitArr,
 	bits,
-	field::BitField,
+	field::{
+		BitField,
+		BitFieldSigned,
+	},
 	order::{
 		BitOrder,
 		LocalBits,
@@ -0,0 +1,392 @@
+/*! Succinct rank/select indexing over a bit-slice.
+
+This module provides the [`RankSelect`] index, which augments a read-only
+[`BitSlice`] region with population-count tables so that the questions “how many
+bits are set before this position” (*rank*) and “where is the *k*th set bit”
+(*select*) can be answered without walking the region.
+
+The index is built once, in a single pass over the [`domain`] of the slice, and
+costs a little over three percent of the slice size in additional memory. Rank
+queries are constant-time; select queries are logarithmic in the slice length.
+
+[`BitSlice`]: crate::slice::BitSlice
+[`RankSelect`]: self::RankSelect
+[`domain`]: crate::slice::BitSlice::domain
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use alloc::vec::Vec;
+
+use core::ops::Deref;
+
+/// The number of bits summarized by each entry in the block table.
+const BLOCK: usize = 512;
+
+/// The number of blocks summarized by each entry in the superblock table.
+///
+/// This is chosen so that the in-superblock counts always fit in a `u16`.
+const SUPER: usize = 128;
+
+/** A rank/select index over a bit-slice.
+
+This type holds a handle to a [`BitSlice`] region, which may be either owned
+(such as a [`BitBox`] or [`BitVec`]) or borrowed (`&BitSlice`), and a pair of
+cumulative population-count tables over it. The tables are taken at the time of
+construction, so the index does not permit modification of the underlying bits.
+
+# Type Parameters
+
+- `B`: A handle which dereferences to a `BitSlice<O, T>`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::rank::RankSelect;
+
+let bits = bits![0, 1, 1, 0, 0, 1, 0, 1];
+let index = RankSelect::new(bits);
+
+assert_eq!(index.rank1(3), 2);
+assert_eq!(index.rank0(3), 1);
+assert_eq!(index.select1(2), Some(5));
+assert_eq!(index.select0(3), Some(6));
+assert_eq!(index.select1(4), None);
+```
+
+[`BitBox`]: crate::boxed::BitBox
+[`BitSlice`]: crate::slice::BitSlice
+[`BitVec`]: crate::vec::BitVec
+**/
+#[derive(Clone, Debug)]
+pub struct RankSelect<B> {
+	/// The indexed bit-slice.
+	bits: B,
+	/// The number of set bits preceding each superblock.
+	supers: Vec<usize>,
+	/// The number of set bits preceding each block, counted from the start of
+	/// the block’s superblock.
+	blocks: Vec<u16>,
+	/// The total number of set bits in the slice.
+	ones: usize,
+}
+
+impl<B, O, T> RankSelect<B>
+where
+	B: Deref<Target = BitSlice<O, T>>,
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Builds a rank/select index over a bit-slice.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: A handle to the bit-slice being indexed. This can be a
+	///   reference, or an owning container such as `BitBox`.
+	///
+	/// # Returns
+	///
+	/// An index over `bits`, with its population-count tables computed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let index = RankSelect::new(bitbox![0, 1, 0, 1]);
+	/// assert_eq!(index.count_ones(), 2);
+	/// ```
+	pub fn new(bits: B) -> Self {
+		let blocks_len = bits.chunks(BLOCK).len();
+		let mut supers = Vec::with_capacity(blocks_len / SUPER + 1);
+		let mut blocks = Vec::with_capacity(blocks_len);
+		let mut ones = 0;
+		let mut local = 0;
+
+		for (idx, chunk) in bits.chunks(BLOCK).enumerate() {
+			if idx % SUPER == 0 {
+				supers.push(ones);
+				local = 0;
+			}
+			blocks.push(local as u16);
+			let count = chunk.count_ones();
+			local += count;
+			ones += count;
+		}
+
+		Self {
+			bits,
+			supers,
+			blocks,
+			ones,
+		}
+	}
+
+	/// Views the indexed bit-slice.
+	pub fn as_bitslice(&self) -> &BitSlice<O, T> {
+		&self.bits
+	}
+
+	/// Removes the index tables, returning the bit-slice handle.
+	pub fn into_inner(self) -> B {
+		self.bits
+	}
+
+	/// Gets the number of bits in the indexed slice.
+	pub fn len(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Tests if the indexed slice is empty.
+	pub fn is_empty(&self) -> bool {
+		self.bits.is_empty()
+	}
+
+	/// Counts the number of bits set to `1` in the indexed slice.
+	///
+	/// This is cached at construction, and does not walk the slice.
+	pub fn count_ones(&self) -> usize {
+		self.ones
+	}
+
+	/// Counts the number of bits cleared to `0` in the indexed slice.
+	///
+	/// This is cached at construction, and does not walk the slice.
+	pub fn count_zeros(&self) -> usize {
+		self.len() - self.ones
+	}
+
+	/// Counts the number of bits set to `1` before a position.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: A position in the slice. This may be equal to the slice
+	///   length, in which case the whole slice is counted.
+	///
+	/// # Returns
+	///
+	/// The number of `1` bits in `self.as_bitslice()[.. index]`.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is greater than the slice length.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![1, 0, 1, 1];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.rank1(0), 0);
+	/// assert_eq!(index.rank1(2), 1);
+	/// assert_eq!(index.rank1(4), 3);
+	/// ```
+	pub fn rank1(&self, index: usize) -> usize {
+		let len = self.len();
+		assert!(
+			index <= len,
+			"Index {} out of range for rank over length {}",
+			index,
+			len
+		);
+		let block = index / BLOCK;
+		if block == self.blocks.len() {
+			return self.ones;
+		}
+		self.ones_before_block(block)
+			+ self.bits[block * BLOCK .. index].count_ones()
+	}
+
+	/// Counts the number of bits cleared to `0` before a position.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: A position in the slice. This may be equal to the slice
+	///   length, in which case the whole slice is counted.
+	///
+	/// # Returns
+	///
+	/// The number of `0` bits in `self.as_bitslice()[.. index]`.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is greater than the slice length.
+	pub fn rank0(&self, index: usize) -> usize {
+		index - self.rank1(index)
+	}
+
+	/// Finds the position of a bit set to `1`, by its rank.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `rank`: The zero-based count of the `1` bit to find. `select1(0)` is
+	///   the first set bit in the slice.
+	///
+	/// # Returns
+	///
+	/// The index of the `rank`th `1` bit in the slice, if there are more than
+	/// `rank` set bits. For any `rank` that produces `Some(idx)`,
+	/// `rank1(idx) == rank`.
+	pub fn select1(&self, rank: usize) -> Option<usize> {
+		if rank >= self.ones {
+			return None;
+		}
+		let block = self.find_block(rank, |this, block| {
+			this.ones_before_block(block)
+		});
+		let rest = rank - self.ones_before_block(block);
+		Self::select_in(&self.bits[block * BLOCK ..], rest, true)
+			.map(|idx| block * BLOCK + idx)
+	}
+
+	/// Finds the position of a bit cleared to `0`, by its rank.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `rank`: The zero-based count of the `0` bit to find. `select0(0)` is
+	///   the first cleared bit in the slice.
+	///
+	/// # Returns
+	///
+	/// The index of the `rank`th `0` bit in the slice, if there are more than
+	/// `rank` cleared bits. For any `rank` that produces `Some(idx)`,
+	/// `rank0(idx) == rank`.
+	pub fn select0(&self, rank: usize) -> Option<usize> {
+		if rank >= self.count_zeros() {
+			return None;
+		}
+		let block = self.find_block(rank, |this, block| {
+			block * BLOCK - this.ones_before_block(block)
+		});
+		let rest = rank - (block * BLOCK - self.ones_before_block(block));
+		Self::select_in(&self.bits[block * BLOCK ..], rest, false)
+			.map(|idx| block * BLOCK + idx)
+	}
+
+	/// Counts the number of set bits before the start of a block.
+	fn ones_before_block(&self, block: usize) -> usize {
+		self.supers[block / SUPER] + self.blocks[block] as usize
+	}
+
+	/// Finds the last block whose preceding count is not greater than `rank`.
+	///
+	/// `before` must be monotonically non-decreasing over the block indices,
+	/// and `before(0)` must be zero.
+	fn find_block<F>(&self, rank: usize, before: F) -> usize
+	where F: Fn(&Self, usize) -> usize {
+		//  Binary search over `[lo, hi)` for the last block that starts at or
+		//  before the sought bit.
+		let (mut lo, mut hi) = (0, self.blocks.len());
+		while hi - lo > 1 {
+			let mid = lo + (hi - lo) / 2;
+			if before(self, mid) <= rank {
+				lo = mid;
+			}
+			else {
+				hi = mid;
+			}
+		}
+		lo
+	}
+
+	/// Finds the `rank`th bit equal to `bit` within a block.
+	///
+	/// This narrows the search to a single word by counting, then walks the
+	/// remaining bits.
+	fn select_in(
+		bits: &BitSlice<O, T>,
+		mut rank: usize,
+		bit: bool,
+	) -> Option<usize>
+	{
+		let bits = &bits[.. BLOCK.min(bits.len())];
+		let mut offset = 0;
+		for chunk in bits.chunks(64) {
+			let count = if bit {
+				chunk.count_ones()
+			}
+			else {
+				chunk.count_zeros()
+			};
+			if rank < count {
+				return chunk
+					.iter()
+					.enumerate()
+					.filter(|(_, b)| **b == bit)
+					.nth(rank)
+					.map(|(idx, _)| offset + idx);
+			}
+			rank -= count;
+			offset += chunk.len();
+		}
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn rank_select() {
+		let mut bv = bitvec![Msb0, u16; 0; 200_000];
+		for idx in (0 .. bv.len()).filter(|n| n % 7 == 3 || n % 1000 == 0) {
+			bv.set(idx, true);
+		}
+		let index = RankSelect::new(&bv[3 ..]);
+		let bits = index.as_bitslice();
+
+		assert_eq!(index.count_ones(), bits.count_ones());
+		for idx in (0 ..= bits.len()).step_by(997).chain(Some(bits.len())) {
+			assert_eq!(index.rank1(idx), bits[.. idx].count_ones());
+			assert_eq!(index.rank0(idx), bits[.. idx].count_zeros());
+		}
+
+		for (rank, idx) in bits.iter_ones().enumerate().step_by(113) {
+			assert_eq!(index.select1(rank), Some(idx));
+			assert_eq!(index.rank1(idx), rank);
+		}
+		for (rank, idx) in bits.iter_zeros().enumerate().step_by(811) {
+			assert_eq!(index.select0(rank), Some(idx));
+			assert_eq!(index.rank0(idx), rank);
+		}
+		assert!(index.select1(index.count_ones()).is_none());
+		assert!(index.select0(index.count_zeros()).is_none());
+	}
+
+	#[test]
+	fn owned_and_empty() {
+		let index = RankSelect::new(bitbox![Lsb0, u8; 1; 1024]);
+		assert_eq!(index.rank1(1024), 1024);
+		assert_eq!(index.select1(1023), Some(1023));
+		assert_eq!(index.select0(0), None);
+		assert_eq!(index.into_inner().len(), 1024);
+
+		let index = RankSelect::new(BitSlice::<Lsb0, usize>::empty());
+		assert!(index.is_empty());
+		assert_eq!(index.rank1(0), 0);
+		assert_eq!(index.select1(0), None);
+		assert_eq!(index.select0(0), None);
+	}
+
+	#[test]
+	#[should_panic]
+	fn rank_out_of_bounds() {
+		RankSelect::new(bits![0, 1]).rank1(3);
+	}
+}
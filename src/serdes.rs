@@ -3,39 +3,82 @@
 This module implements the Serde traits for the [`bitvec`] types.
 
 As [`BitArray`] does not use dynamic indexing for its starting index or its
-length, it implements [`Deserialize`] and [`Serialize`] by forwarding to the
-interior buffer, and adds no additional information. This also renders it
-incapable of deserializing a serialized [`BitSlice`]; it can only deserialize
-bare value sequences.
+length, it implements [`Serialize`] by forwarding to the interior buffer, and
+adds no additional information. Its [`Deserialize`] implementation reads the
+same bare value sequences. In human-readable formats, such as JSON, which
+describe the structure of their payloads, it also reads the [`BitSlice`] format,
+as long as the `bits` field is the length of the array. Other formats, such as
+`bincode`, do not record which of the two forms a payload has, and so can only
+deserialize the bare form. The [`bitseq`] submodule de/serializes [`BitArray`]
+fields in the [`BitSlice`] format in every protocol.
 
-[`BitSlice`] is able to implement [`Serialize`], but [`serde`] does not provide
-a behavior to deserialize data into a buffer provided by the calling context, so
-it cannot deserialize into any of the owning structures.
+[`BitSlice`] implements [`Serialize`]. [`serde`] does not provide a behavior to
+deserialize data into a buffer provided by the calling context, so it cannot
+deserialize into any of the owning structures. However, `&BitSlice<O, u8>` can
+be deserialized by borrowing its memory directly out of the input, in formats
+that support borrowing byte strings.
 
 [`BitBox`] and [`BitVec`] implement [`Serialize`] through [`BitSlice`], and can
 deserialize the [`BitSlice`] format into themselves.
 
-If you require de/serialization compatibility between [`BitArray`] and the other
-structures, please file an issue.
+The [`BitSlice`] format is a struct named `BitSeq`, with the fields `order` (the
+name of the [`BitOrder`] type parameter), `width` (the bit width of the memory
+elements), `head` (the index of the first live bit in the first element), `bits`
+(the number of live bits), and `data` (the memory elements). Deserialization
+accepts data written with any element type and either of [`Lsb0`] or [`Msb0`],
+and transcodes it into the destination layout. Data written with any other
+ordering must be deserialized with that same ordering. Payloads written before
+the `order` and `width` fields were added are assumed to already be in the
+destination layout. They can only be recognized in formats that record field
+names, such as JSON, or the lengths of sequences. Formats that record neither,
+such as `bincode`, cannot read them.
+
+[`CompressedBitmap`] serializes as a sequence of its chunks, each a tuple of its
+key, a tag for its storage form, and its storage. Deserialization checks that
+the chunks are sorted and well-formed.
+
+The [`bytes`] and [`string`] submodules provide alternate representations for
+use with the `#[serde(with)]` field attribute: [`bytes`] writes `u8`-backed
+bit-sequences as compact byte strings, and [`string`] writes any bit-sequence as
+a human-readable string of binary digits.
 
 [`BitArray`]: crate::array::BitArray
 [`BitBox`]: crate::boxed::BitBox
+[`BitOrder`]: crate::order::BitOrder
 [`BitSlice`]: crate::slice::BitSlice
 [`BitVec`]: crate::vec::BitVec
+[`CompressedBitmap`]: crate::compressed::CompressedBitmap
 [`Deserialize`]: serde::de::Deserialize
+[`Lsb0`]: crate::order::Lsb0
+[`Msb0`]: crate::order::Msb0
 [`Serialize`]: serde::ser::Serialize
+[`bitseq`]: self::bitseq
 [`bitvec`]: crate
+[`bytes`]: self::bytes
 [`serde`]: serde
+[`string`]: self::string
 !*/
 
 #![cfg(feature = "serde")]
+//  The examples in these modules indent with tabs, as the source does.
+#![allow(clippy::tabs_in_doc_comments)]
+
+pub mod bitseq;
+#[cfg(feature = "alloc")]
+pub mod bytes;
+#[cfg(feature = "alloc")]
+pub mod string;
 
 use crate::{
 	array::BitArray,
 	domain::Domain,
 	index::BitIdxErr,
 	mem::BitMemory,
-	order::BitOrder,
+	order::{
+		BitOrder,
+		Lsb0,
+		Msb0,
+	},
 	ptr::BitPtr,
 	slice::BitSlice,
 	store::BitStore,
@@ -43,6 +86,10 @@ use crate::{
 };
 
 use core::{
+	any::{
+		self,
+		TypeId,
+	},
 	cmp,
 	convert::TryInto,
 	fmt::{
@@ -56,8 +103,10 @@ use core::{
 use serde::{
 	de::{
 		self,
+		value::SeqAccessDeserializer,
 		Deserialize,
 		Deserializer,
+		IntoDeserializer,
 		MapAccess,
 		SeqAccess,
 		Unexpected,
@@ -67,6 +116,7 @@ use serde::{
 		Serialize,
 		SerializeSeq,
 		SerializeStruct,
+		SerializeTuple,
 		Serializer,
 	},
 };
@@ -76,9 +126,46 @@ use tap::pipe::Pipe;
 #[cfg(feature = "alloc")]
 use crate::{
 	boxed::BitBox,
+	compressed::{
+		CompressedBitmap,
+		Container,
+		Dense,
+	},
 	vec::BitVec,
 };
 
+#[cfg(feature = "alloc")]
+use serde::de::DeserializeSeed;
+
+#[cfg(feature = "alloc")]
+use core::convert::TryFrom;
+
+#[cfg(feature = "alloc")]
+use alloc::{
+	boxed::Box,
+	string::String,
+	vec::Vec,
+};
+
+/// Gets the name that the `BitSeq` format records for a bit-ordering.
+///
+/// The orderings in this crate are recorded by their bare names, so that the
+/// name does not depend on the crate version or path. Other orderings are
+/// recorded by their full type name.
+pub(crate) fn order_name<O>() -> &'static str
+where O: BitOrder {
+	let id = TypeId::of::<O>();
+	if id == TypeId::of::<Lsb0>() {
+		"Lsb0"
+	}
+	else if id == TypeId::of::<Msb0>() {
+		"Msb0"
+	}
+	else {
+		any::type_name::<O>()
+	}
+}
+
 impl<O, T> Serialize for BitSlice<O, T>
 where
 	O: BitOrder,
@@ -88,8 +175,10 @@ where
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where S: Serializer {
 		let head = self.bitptr().head();
-		let mut state = serializer.serialize_struct("BitSeq", 3)?;
+		let mut state = serializer.serialize_struct("BitSeq", 5)?;
 
+		state.serialize_field("order", order_name::<O>())?;
+		state.serialize_field("width", &T::Mem::BITS)?;
 		state.serialize_field("head", &head.value())?;
 		state.serialize_field("bits", &(self.len() as u64))?;
 		state.serialize_field("data", &self.domain())?;
@@ -121,8 +210,9 @@ where
 {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where S: Serializer {
+		//  Arrays deserialize from tuples, which may omit their length.
 		let ary = self.as_slice();
-		let mut state = serializer.serialize_seq(Some(ary.len()))?;
+		let mut state = serializer.serialize_tuple(ary.len())?;
 		for elem in ary.iter().map(BitStore::load_value) {
 			state.serialize_element(&elem)?;
 		}
@@ -160,12 +250,73 @@ impl<'de, O, V> Deserialize<'de> for BitArray<O, V>
 where
 	O: BitOrder,
 	V: BitView + Deserialize<'de>,
+	<V::Store as BitStore>::Mem: Deserialize<'de>,
 {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: Deserializer<'de> {
-		deserializer
-			.pipe(<V as Deserialize<'de>>::deserialize)
-			.map(Self::new)
+		//  Only formats that describe themselves can say which form is present.
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_any(BitArrayVisitor::<O, V>(PhantomData))
+		}
+		else {
+			deserializer
+				.pipe(<V as Deserialize<'de>>::deserialize)
+				.map(Self::new)
+		}
+	}
+}
+
+/** Aid for deserializing either form of a [`BitArray`].
+
+Bare values are forwarded to the interior buffer, and maps are read as the
+`BitSeq` format.
+
+[`BitArray`]: crate::array::BitArray
+**/
+struct BitArrayVisitor<O, V>(PhantomData<BitArray<O, V>>)
+where
+	O: BitOrder,
+	V: BitView;
+
+impl<'de, O, V> Visitor<'de> for BitArrayVisitor<O, V>
+where
+	O: BitOrder,
+	V: BitView + Deserialize<'de>,
+	<V::Store as BitStore>::Mem: Deserialize<'de>,
+{
+	type Value = BitArray<O, V>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(
+			fmt,
+			"a sequence of memory elements, or a BitSeq data series of {} bits",
+			V::const_bits(),
+		)
+	}
+
+	/// Visit a single memory element, as a scalar array serializes.
+	fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+	where E: de::Error {
+		V::deserialize(value.into_deserializer()).map(BitArray::new)
+	}
+
+	/// Visit a single memory element, from formats that store all integers as
+	/// signed.
+	fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+	where E: de::Error {
+		V::deserialize(value.into_deserializer()).map(BitArray::new)
+	}
+
+	/// Visit a sequence of memory elements.
+	fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+	where A: SeqAccess<'de> {
+		V::deserialize(SeqAccessDeserializer::new(seq)).map(BitArray::new)
+	}
+
+	/// Visit the named fields of the `BitSeq` format.
+	fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+	where A: MapAccess<'de> {
+		bitseq::ArrayVisitor::<O, V>::THIS.visit_map(map)
 	}
 }
 
@@ -195,6 +346,38 @@ where
 		_bv: PhantomData,
 	};
 
+	/// Constructs a [`BitVec`] from deserialized components.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: A visitor, only needed for access to an error message.
+	/// - `head`: The deserialized head-bit index.
+	/// - `bits`: The deserialized length counter.
+	/// - `data`: The memory containing the bitslice, in the layout that it was
+	///   deserialized with.
+	///
+	/// # Returns
+	///
+	/// The result of assembling the deserialized components into a [`BitVec`].
+	///
+	/// [`BitVec`]: crate::vec::BitVec
+	fn finish<E>(
+		&self,
+		head: u8,
+		bits: u64,
+		data: Data<T>,
+	) -> Result<<Self as Visitor<'de>>::Value, E>
+	where
+		E: de::Error,
+	{
+		match data {
+			Data::Native(data) => self.assemble(head, bits as usize, data),
+			Data::Foreign { msb0, width, words } => {
+				Self::transcode(msb0, width, head, bits, words)
+			},
+		}
+	}
+
 	/// Constructs a [`BitVec`] from deserialized components.
 	///
 	/// # Parameters
@@ -247,6 +430,106 @@ where
 		.map(BitPtr::to_bitslice_ptr_mut)
 		.map(|bp| unsafe { BitVec::from_raw_parts(bp, data.capacity()) })
 	}
+
+	/// Converts data elements that were read as `u64`, before their layout was
+	/// known, into that layout.
+	///
+	/// # Parameters
+	///
+	/// - `layout`: The layout in which the data was written.
+	/// - `words`: The deserialized memory elements, each read as `u64`.
+	///
+	/// # Returns
+	///
+	/// The data in `layout`, or an error if an element does not fit in the
+	/// width that `layout` describes.
+	fn narrow<E>(layout: Layout, words: Vec<u64>) -> Result<Data<T>, E>
+	where E: de::Error {
+		let width = match layout {
+			Layout::Native => T::Mem::BITS,
+			Layout::Foreign { width, .. } => width,
+		};
+		if let Some(&word) = words
+			.iter()
+			.find(|&&word| width < 64 && word >> width != 0)
+		{
+			return Err(de::Error::invalid_value(
+				Unexpected::Unsigned(word),
+				&"a memory element of the serialized width",
+			));
+		}
+		match layout {
+			Layout::Native => {
+				let mut out = BitVec::<O, T>::repeat(
+					false,
+					words.len() * T::Mem::BITS as usize,
+				);
+				for (elem, &word) in out.as_mut_slice().iter_mut().zip(&words) {
+					//  The range check above ensures that this succeeds.
+					if let Ok(word) = T::Mem::try_from(word) {
+						elem.store_value(word);
+					}
+				}
+				Ok(Data::Native(out.into_vec()))
+			},
+			Layout::Foreign { msb0, width } => {
+				Ok(Data::Foreign { msb0, width, words })
+			},
+		}
+	}
+
+	/// Copies bits out of memory written in a different layout than `O` and
+	/// `T`.
+	///
+	/// # Parameters
+	///
+	/// - `msb0`: Whether the memory was written with `Msb0` or `Lsb0`.
+	/// - `width`: The bit width of the elements that the memory was written
+	///   with.
+	/// - `head`: The deserialized head-bit index.
+	/// - `bits`: The deserialized length counter.
+	/// - `words`: The deserialized memory elements, each widened to `u64`.
+	///
+	/// # Returns
+	///
+	/// A [`BitVec`] holding the same sequence of bits that was serialized, or
+	/// an error if the `head` is invalid.
+	///
+	/// [`BitVec`]: crate::vec::BitVec
+	fn transcode<E>(
+		msb0: bool,
+		width: u8,
+		head: u8,
+		bits: u64,
+		words: Vec<u64>,
+	) -> Result<<Self as Visitor<'de>>::Value, E>
+	where
+		E: de::Error,
+	{
+		if head >= width {
+			return Err(de::Error::invalid_value(
+				Unexpected::Unsigned(head as u64),
+				&"a head-bit index less than the serialized element width",
+			));
+		}
+		let (width, head) = (width as u64, head as u64);
+		let bits = (words.len() as u64 * width)
+			.checked_sub(head)
+			.ok_or_else(|| {
+				de::Error::invalid_length(
+					0,
+					&"a memory element holding the head-bit index",
+				)
+			})?
+			.pipe(|capacity| cmp::min(bits, capacity));
+		let mut out = BitVec::with_capacity(bits as usize);
+		for idx in head .. head + bits {
+			let (word, bit) = (words[(idx / width) as usize], idx % width);
+			let shift = if msb0 { width - 1 - bit } else { bit };
+			out.push(word >> shift & 1 != 0);
+		}
+		Ok(out)
+	}
 }
 
 #[cfg(feature = "alloc")]
@@ -262,64 +545,302 @@ where
 	}
 
 	/// Visit a sequence of anonymous data elements. These must be in the order
-	/// `u8` (head-bit index), `u64` (length counter), `[T]` (data contents).
+	/// `str` (bit-order name), `u8` (element width), `u8` (head-bit index),
+	/// `u64` (length counter), `[T]` (data contents).
+	///
+	/// Sequences that report a length of three or fewer elements are read in
+	/// the older layout, which omits the bit-order name and element width.
+	/// Formats that do not record sequence lengths report the length of the
+	/// current layout instead, and cannot be read in the older layout.
 	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
 	where V: SeqAccess<'de> {
+		let legacy = matches!(seq.size_hint(), Some(len) if len <= 3);
+		let (order, width, skip) = if legacy {
+			(None, None, 0)
+		}
+		else {
+			let order = seq
+				.next_element::<String>()?
+				.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+			let width = seq
+				.next_element::<u8>()?
+				.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+			(Some(order), Some(width), 2)
+		};
+		let layout = find_layout::<O, T::Mem, _>(order.as_deref(), width)?;
+
 		let head = seq
 			.next_element::<u8>()?
-			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+			.ok_or_else(|| de::Error::invalid_length(skip, &self))?;
 		let bits = seq
 			.next_element::<u64>()?
-			.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+			.ok_or_else(|| de::Error::invalid_length(skip + 1, &self))?;
 		let data = seq
-			.next_element::<Vec<T>>()?
-			.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+			.next_element_seed(DataSeed::<T>::new(layout))?
+			.ok_or_else(|| de::Error::invalid_length(skip + 2, &self))?;
 
-		self.assemble(head, bits as usize, data)
+		self.finish(head, bits, data)
 	}
 
 	/// Visit a map of named data elements. These may be in any order, and must
-	/// be the pairs `head: u8`, `bits: u64`, and `data: [T]`.
+	/// be the pairs `head: u8`, `bits: u64`, and `data: [T]`, optionally with
+	/// `order: str` and `width: u8`. When `data` precedes `order` or `width`,
+	/// its elements are read as `u64` and narrowed once the layout is known.
 	fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
 	where V: MapAccess<'de> {
+		let mut order: Option<String> = None;
+		let mut width: Option<u8> = None;
 		let mut head: Option<u8> = None;
 		let mut bits: Option<u64> = None;
-		let mut data: Option<Vec<T>> = None;
+		let mut data: Option<Data<T>> = None;
+		//  Data elements that arrived before the fields describing them.
+		let mut early: Option<Vec<u64>> = None;
 
 		while let Some(key) = map.next_key()? {
 			match key {
-				"head" => {
+				Field::Order => {
+					if order.replace(map.next_value()?).is_some() {
+						return Err(de::Error::duplicate_field("order"));
+					}
+				},
+				Field::Width => {
+					if width.replace(map.next_value()?).is_some() {
+						return Err(de::Error::duplicate_field("width"));
+					}
+				},
+				Field::Head => {
 					if head.replace(map.next_value()?).is_some() {
 						return Err(de::Error::duplicate_field("head"));
 					}
 				},
-				"bits" => {
+				Field::Bits => {
 					if bits.replace(map.next_value()?).is_some() {
 						return Err(de::Error::duplicate_field("bits"));
 					}
 				},
-				"data" => {
-					if data.replace(map.next_value()?).is_some() {
+				Field::Data => {
+					let dup = if order.is_some() && width.is_some() {
+						let layout = find_layout::<O, T::Mem, _>(
+							order.as_deref(),
+							width,
+						)?;
+						let seed = DataSeed::new(layout);
+						data.replace(map.next_value_seed(seed)?).is_some()
+							|| early.is_some()
+					}
+					else {
+						early.replace(map.next_value()?).is_some()
+							|| data.is_some()
+					};
+					if dup {
 						return Err(de::Error::duplicate_field("data"));
 					}
 				},
-				f => {
-					/* Once a key is pulled from the map, a value **must** also
-					be pulled, otherwise `serde` will fail with its own error
-					rather than this one.
-					*/
-					let _ = map.next_value::<()>();
-					return Err(de::Error::unknown_field(f, &[
-						"head", "bits", "data",
-					]));
-				},
 			}
 		}
 		let head = head.ok_or_else(|| de::Error::missing_field("head"))?;
 		let bits = bits.ok_or_else(|| de::Error::missing_field("bits"))?;
-		let data = data.ok_or_else(|| de::Error::missing_field("data"))?;
+		let data = match (data, early) {
+			(Some(data), _) => data,
+			(None, Some(words)) => {
+				let layout =
+					find_layout::<O, T::Mem, V::Error>(order.as_deref(), width)?;
+				Self::narrow(layout, words)?
+			},
+			(None, None) => return Err(de::Error::missing_field("data")),
+		};
+
+		self.finish(head, bits, data)
+	}
+}
+
+/// Determines how to read the `data` field of a `BitSeq`.
+///
+/// # Type Parameters
+///
+/// - `O`: The bit-order of the destination.
+/// - `M`: The memory element of the destination.
+///
+/// # Parameters
+///
+/// - `order`: The deserialized bit-order name, if present.
+/// - `width`: The deserialized element width, if present.
+///
+/// # Returns
+///
+/// `Layout::Native` if the serialized layout matches `O` and `M`, or if either
+/// field is missing (as in payloads written before these fields existed).
+/// `Layout::Foreign` if the data was written with one of this crate’s orderings
+/// and a different element or ordering, and can be transcoded. An error
+/// otherwise.
+fn find_layout<O, M, E>(
+	order: Option<&str>,
+	width: Option<u8>,
+) -> Result<Layout, E>
+where
+	O: BitOrder,
+	M: BitMemory,
+	E: de::Error,
+{
+	let (order, width) = match (order, width) {
+		(Some(order), Some(width)) => (order, width),
+		_ => return Ok(Layout::Native),
+	};
+	if order == order_name::<O>() && width == M::BITS {
+		return Ok(Layout::Native);
+	}
+	let msb0 = match order {
+		"Lsb0" => false,
+		"Msb0" => true,
+		_ => {
+			return Err(de::Error::invalid_value(
+				Unexpected::Str(order),
+				&ExpectOrder::<O>(PhantomData),
+			));
+		},
+	};
+	match width {
+		8 | 16 | 32 | 64 => Ok(Layout::Foreign { msb0, width }),
+		_ => Err(de::Error::invalid_value(
+			Unexpected::Unsigned(width as u64),
+			&"an element width of 8, 16, 32, or 64 bits",
+		)),
+	}
+}
+
+/// Describes the bit-orders that a destination ordered by `O` can read.
+struct ExpectOrder<O>(PhantomData<O>)
+where O: BitOrder;
+
+impl<O> de::Expected for ExpectOrder<O>
+where O: BitOrder
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match order_name::<O>() {
+			"Lsb0" | "Msb0" => fmt.write_str("the bit-order `Lsb0` or `Msb0`"),
+			name => write!(fmt, "the bit-order `{}`, `Lsb0`, or `Msb0`", name),
+		}
+	}
+}
+
+/// The names of the fields in the `BitSeq` format.
+const FIELDS: &[&str] = &["order", "width", "head", "bits", "data"];
+
+/// The fields of a `BitSeq`.
+enum Field {
+	Order,
+	Width,
+	Head,
+	Bits,
+	Data,
+}
+
+impl<'de> Deserialize<'de> for Field {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_identifier(FieldVisitor)
+	}
+}
+
+/// Aid for deserializing the name of a `BitSeq` field.
+struct FieldVisitor;
+
+impl<'de> Visitor<'de> for FieldVisitor {
+	type Value = Field;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("the name of a BitSeq field")
+	}
+
+	fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+	where E: de::Error {
+		match value {
+			"order" => Ok(Field::Order),
+			"width" => Ok(Field::Width),
+			"head" => Ok(Field::Head),
+			"bits" => Ok(Field::Bits),
+			"data" => Ok(Field::Data),
+			f => Err(de::Error::unknown_field(f, FIELDS)),
+		}
+	}
+}
+
+/// The memory layout in which a `BitSeq` payload was written.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Layout {
+	/// The layout of the destination type.
+	Native,
+	/// A layout using one of this crate’s orderings and a different element
+	/// type or ordering than the destination.
+	Foreign {
+		/// Whether the payload was written with `Msb0` (or `Lsb0`).
+		msb0: bool,
+		/// The bit width of the payload’s memory elements.
+		width: u8,
+	},
+}
+
+/// The deserialized `data` field of a `BitSeq`.
+#[cfg(feature = "alloc")]
+enum Data<T> {
+	/// Memory in the destination layout.
+	Native(Vec<T>),
+	/// Memory in a foreign layout, widened to `u64` elements.
+	Foreign {
+		msb0: bool,
+		width: u8,
+		words: Vec<u64>,
+	},
+}
+
+/// Deserializes the `data` field of a `BitSeq` in a given layout.
+#[cfg(feature = "alloc")]
+struct DataSeed<T> {
+	layout: Layout,
+	_ty: PhantomData<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> DataSeed<T> {
+	fn new(layout: Layout) -> Self {
+		Self {
+			layout,
+			_ty: PhantomData,
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T> DeserializeSeed<'de> for DataSeed<T>
+where T: BitStore + Deserialize<'de>
+{
+	type Value = Data<T>;
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where D: Deserializer<'de> {
+		/// Deserializes a sequence of `U` and widens it to `u64`.
+		fn widen<'de, D, U>(deserializer: D) -> Result<Vec<u64>, D::Error>
+		where
+			D: Deserializer<'de>,
+			U: Deserialize<'de> + Into<u64>,
+		{
+			Vec::<U>::deserialize(deserializer)
+				.map(|data| data.into_iter().map(Into::into).collect())
+		}
 
-		self.assemble(head, bits as usize, data)
+		let (msb0, width) = match self.layout {
+			Layout::Native => {
+				return Vec::<T>::deserialize(deserializer).map(Data::Native);
+			},
+			Layout::Foreign { msb0, width } => (msb0, width),
+		};
+		let words = match width {
+			8 => widen::<D, u8>(deserializer),
+			16 => widen::<D, u16>(deserializer),
+			32 => widen::<D, u32>(deserializer),
+			_ => widen::<D, u64>(deserializer),
+		}?;
+		Ok(Data::Foreign { msb0, width, words })
 	}
 }
 
@@ -342,17 +863,380 @@ impl<'de, O, T> Deserialize<'de> for BitVec<O, T>
 where
 	O: BitOrder,
 	T: BitStore + Deserialize<'de>,
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_struct("BitSeq", FIELDS, BitVecVisitor::THIS)
+	}
+}
+
+/** Borrows a bit-slice out of the deserializer’s input, without copying it.
+
+The `data` field must be borrowed from the input as a byte string. Formats such
+as bincode and postcard, which encode a sequence of bytes the same way as a
+byte string, can therefore read the `BitSeq` payloads written by any `u8`-backed
+bit-sequence. Formats that cannot lend out their input, such as JSON, cannot use
+this implementation, and must deserialize into a [`BitVec`] or [`BitBox`]
+instead.
+
+The payload must have been written with the same ordering `O` and with `u8`
+elements, since memory in any other layout would need to be copied to be
+transcoded.
+
+[`BitBox`]: crate::boxed::BitBox
+[`BitVec`]: crate::vec::BitVec
+**/
+impl<'de, 'a, O> Deserialize<'de> for &'a BitSlice<O, u8>
+where
+	'de: 'a,
+	O: BitOrder,
 {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: Deserializer<'de> {
 		deserializer.deserialize_struct(
 			"BitSeq",
-			&["head", "bits", "data"],
-			BitVecVisitor::THIS,
+			FIELDS,
+			BitSliceVisitor::<'a, O>(PhantomData),
 		)
 	}
 }
 
+/// Aid for deserializing a protocol into a borrowed [`BitSlice`].
+///
+/// [`BitSlice`]: crate::slice::BitSlice
+struct BitSliceVisitor<'a, O>(PhantomData<&'a BitSlice<O, u8>>)
+where O: BitOrder;
+
+impl<'a, O> BitSliceVisitor<'a, O>
+where O: BitOrder
+{
+	/// Checks that a payload was written in the layout of `BitSlice<O, u8>`.
+	///
+	/// Payloads that do not record their layout are assumed to match.
+	fn check<E>(&self, order: Option<&str>, width: Option<u8>) -> Result<(), E>
+	where E: de::Error {
+		if let Some(order) = order {
+			if order != order_name::<O>() {
+				return Err(de::Error::invalid_value(
+					Unexpected::Str(order),
+					&order_name::<O>(),
+				));
+			}
+		}
+		match width {
+			None | Some(8) => Ok(()),
+			Some(width) => Err(de::Error::invalid_value(
+				Unexpected::Unsigned(width as u64),
+				&"an element width of 8 bits",
+			)),
+		}
+	}
+
+	/// Constructs a [`BitSlice`] reference from deserialized components.
+	///
+	/// As with the owning deserializers, a `bits` counter larger than the data
+	/// is reduced to fit it.
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	fn finish<E>(
+		&self,
+		head: u8,
+		bits: u64,
+		data: &'a [u8],
+	) -> Result<&'a BitSlice<O, u8>, E>
+	where
+		E: de::Error,
+	{
+		if head >= 8 {
+			return Err(de::Error::invalid_value(
+				Unexpected::Unsigned(head as u64),
+				&"a head-bit index less than 8",
+			));
+		}
+		let bits = (data.len() as u64 * 8)
+			.checked_sub(head as u64)
+			.ok_or_else(|| {
+				de::Error::invalid_length(
+					0,
+					&"a memory element holding the head-bit index",
+				)
+			})?
+			.pipe(|capacity| cmp::min(bits, capacity));
+		unsafe {
+			crate::slice::bits_from_raw_parts(
+				data.as_ptr(),
+				head,
+				bits as usize,
+			)
+		}
+		.ok_or_else(|| {
+			de::Error::invalid_value(
+				Unexpected::Other("invalid bit-region source data"),
+				self,
+			)
+		})
+	}
+}
+
+impl<'de, 'a, O> Visitor<'de> for BitSliceVisitor<'a, O>
+where
+	'de: 'a,
+	O: BitOrder,
+{
+	type Value = &'a BitSlice<O, u8>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("a BitSeq data series with borrowed `u8` data")
+	}
+
+	/// Visit a sequence of anonymous data elements, in the same order as the
+	/// owning deserializers accept. As there, the older layout is only
+	/// recognized in formats that report a length of three or fewer elements.
+	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		let legacy = matches!(seq.size_hint(), Some(len) if len <= 3);
+		let skip = if legacy {
+			0
+		}
+		else {
+			let order = seq
+				.next_element::<&'de str>()?
+				.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+			let width = seq
+				.next_element::<u8>()?
+				.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+			self.check(Some(order), Some(width))?;
+			2
+		};
+
+		let head = seq
+			.next_element::<u8>()?
+			.ok_or_else(|| de::Error::invalid_length(skip, &self))?;
+		let bits = seq
+			.next_element::<u64>()?
+			.ok_or_else(|| de::Error::invalid_length(skip + 1, &self))?;
+		let data = seq
+			.next_element::<&'de [u8]>()?
+			.ok_or_else(|| de::Error::invalid_length(skip + 2, &self))?;
+
+		self.finish(head, bits, data)
+	}
+
+	/// Visit a map of named data elements, with the same keys as the owning
+	/// deserializers accept.
+	fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+	where V: MapAccess<'de> {
+		let mut order: Option<&'de str> = None;
+		let mut width: Option<u8> = None;
+		let mut head: Option<u8> = None;
+		let mut bits: Option<u64> = None;
+		let mut data: Option<&'de [u8]> = None;
+
+		while let Some(key) = map.next_key()? {
+			match key {
+				Field::Order => {
+					if order.replace(map.next_value()?).is_some() {
+						return Err(de::Error::duplicate_field("order"));
+					}
+				},
+				Field::Width => {
+					if width.replace(map.next_value()?).is_some() {
+						return Err(de::Error::duplicate_field("width"));
+					}
+				},
+				Field::Head => {
+					if head.replace(map.next_value()?).is_some() {
+						return Err(de::Error::duplicate_field("head"));
+					}
+				},
+				Field::Bits => {
+					if bits.replace(map.next_value()?).is_some() {
+						return Err(de::Error::duplicate_field("bits"));
+					}
+				},
+				Field::Data => {
+					if data.replace(map.next_value()?).is_some() {
+						return Err(de::Error::duplicate_field("data"));
+					}
+				},
+			}
+		}
+		self.check(order, width)?;
+		let head = head.ok_or_else(|| de::Error::missing_field("head"))?;
+		let bits = bits.ok_or_else(|| de::Error::missing_field("bits"))?;
+		let data = data.ok_or_else(|| de::Error::missing_field("data"))?;
+
+		self.finish(head, bits, data)
+	}
+}
+
+/// Marks a compressed chunk whose data is a sorted list of members.
+#[cfg(feature = "alloc")]
+const CHUNK_ARRAY: u8 = 0;
+
+/// Marks a compressed chunk whose data is a list of `(first, last)` runs.
+#[cfg(feature = "alloc")]
+const CHUNK_RUNS: u8 = 1;
+
+/// Marks a compressed chunk whose data is its dense bit-map, as `u64` words.
+#[cfg(feature = "alloc")]
+const CHUNK_DENSE: u8 = 2;
+
+/// A [`CompressedBitmap`] serializes as a sequence of `(key, kind, data)`
+/// chunk tuples, where `kind` selects the format of `data`.
+///
+/// [`CompressedBitmap`]: crate::compressed::CompressedBitmap
+#[cfg(feature = "alloc")]
+impl Serialize for CompressedBitmap {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer {
+		let mut state = serializer.serialize_seq(Some(self.chunks.len()))?;
+		for (key, chunk) in &self.chunks {
+			state.serialize_element(&ChunkRef(*key, chunk))?;
+		}
+		state.end()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for CompressedBitmap {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_seq(CompressedBitmapVisitor)
+	}
+}
+
+/// Aid for serializing one chunk of a [`CompressedBitmap`].
+///
+/// [`CompressedBitmap`]: crate::compressed::CompressedBitmap
+#[cfg(feature = "alloc")]
+struct ChunkRef<'a>(u16, &'a Container);
+
+#[cfg(feature = "alloc")]
+impl Serialize for ChunkRef<'_> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer {
+		let mut state = serializer.serialize_tuple(3)?;
+		state.serialize_element(&self.0)?;
+		match self.1 {
+			Container::Array(values) => {
+				state.serialize_element(&CHUNK_ARRAY)?;
+				state.serialize_element(values)?;
+			},
+			Container::Runs(runs) => {
+				state.serialize_element(&CHUNK_RUNS)?;
+				state.serialize_element(runs)?;
+			},
+			Container::Dense(bits) => {
+				state.serialize_element(&CHUNK_DENSE)?;
+				state.serialize_element(bits.as_slice())?;
+			},
+		}
+		state.end()
+	}
+}
+
+/// Aid for deserializing one chunk of a [`CompressedBitmap`].
+///
+/// [`CompressedBitmap`]: crate::compressed::CompressedBitmap
+#[cfg(feature = "alloc")]
+struct ChunkOwned(u16, Container);
+
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for ChunkOwned {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_tuple(3, ChunkVisitor)
+	}
+}
+
+/** Aid for deserializing a protocol into a [`CompressedBitmap`].
+
+[`CompressedBitmap`]: crate::compressed::CompressedBitmap
+**/
+#[cfg(feature = "alloc")]
+struct CompressedBitmapVisitor;
+
+#[cfg(feature = "alloc")]
+impl<'de> Visitor<'de> for CompressedBitmapVisitor {
+	type Value = CompressedBitmap;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("a sequence of compressed bit-map chunks")
+	}
+
+	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		//  Do not trust the length hint with more than the key space.
+		let hint = cmp::min(seq.size_hint().unwrap_or(0), 1 << 16);
+		let mut chunks = Vec::with_capacity(hint);
+		while let Some(ChunkOwned(key, chunk)) = seq.next_element()? {
+			chunks.push((key, chunk));
+		}
+		CompressedBitmap::from_chunks(chunks).map_err(|msg| {
+			de::Error::invalid_value(Unexpected::Other(msg), &self)
+		})
+	}
+}
+
+/// Aid for deserializing one chunk of a [`CompressedBitmap`].
+///
+/// [`CompressedBitmap`]: crate::compressed::CompressedBitmap
+#[cfg(feature = "alloc")]
+struct ChunkVisitor;
+
+#[cfg(feature = "alloc")]
+impl<'de> Visitor<'de> for ChunkVisitor {
+	type Value = ChunkOwned;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("a compressed bit-map chunk")
+	}
+
+	/// Visit a sequence of anonymous data elements. These must be in the order
+	/// `u16` (chunk key), `u8` (chunk kind), and the chunk data: `[u16]` for
+	/// arrays, `[(u16, u16)]` for runs, or `[u64; 1024]` for dense chunks.
+	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		let key = seq
+			.next_element::<u16>()?
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+		let kind = seq
+			.next_element::<u8>()?
+			.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+		let missing = || de::Error::invalid_length(2, &self);
+		let chunk = match kind {
+			CHUNK_ARRAY => {
+				Container::Array(seq.next_element()?.ok_or_else(missing)?)
+			},
+			CHUNK_RUNS => {
+				Container::Runs(seq.next_element()?.ok_or_else(missing)?)
+			},
+			CHUNK_DENSE => {
+				let words = seq
+					.next_element::<Vec<u64>>()?
+					.ok_or_else(missing)?;
+				let mut bits = Box::new(Dense::zeroed());
+				if words.len() != bits.as_slice().len() {
+					return Err(de::Error::invalid_length(
+						words.len(),
+						&"1024 words of dense chunk data",
+					));
+				}
+				bits.as_mut_slice().copy_from_slice(&words);
+				Container::Dense(bits)
+			},
+			other => {
+				return Err(de::Error::invalid_value(
+					Unexpected::Unsigned(other as u64),
+					&"a chunk kind of 0 (array), 1 (runs), or 2 (dense)",
+				));
+			},
+		};
+		Ok(ChunkOwned(key, chunk))
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::prelude::*;
@@ -371,9 +1255,11 @@ mod tests {
 	};
 
 	macro_rules! bvtok {
-		( s $elts:expr, $head:expr, $bits:expr, $ty:ident $( , $data:expr )* ) => {
+		( s $ord:ty, $width:expr; $elts:expr, $head:expr, $bits:expr, $ty:ident $( , $data:expr )* ) => {
 			&[
-				Token::Struct { name: "BitSeq", len: 3, },
+				Token::Struct { name: "BitSeq", len: 5, },
+				Token::Str("order"), Token::Str(super::order_name::<$ord>()),
+				Token::Str("width"), Token::U8( $width ),
 				Token::Str("head"), Token::U8( $head ),
 				Token::Str("bits"), Token::U64( $bits ),
 				Token::Str("data"), Token::Seq { len: Some( $elts ) },
@@ -382,6 +1268,29 @@ mod tests {
 				Token::StructEnd,
 			]
 		};
+		( d $ord:expr, $width:expr; $elts:expr, $head:expr, $bits:expr, $ty:ident $( , $data:expr )* ) => {
+			&[
+				Token::Struct { name: "BitSeq", len: 5, },
+				Token::BorrowedStr("order"), Token::BorrowedStr( $ord ),
+				Token::BorrowedStr("width"), Token::U8( $width ),
+				Token::BorrowedStr("head"), Token::U8( $head ),
+				Token::BorrowedStr("bits"), Token::U64( $bits ),
+				Token::BorrowedStr("data"), Token::Seq { len: Some( $elts ) },
+				$( Token:: $ty ( $data ), )*
+				Token::SeqEnd,
+				Token::StructEnd,
+			]
+		};
+		( e $ord:expr, $width:expr ) => {
+			&[
+				Token::Struct { name: "BitSeq", len: 5, },
+				Token::BorrowedStr("order"), Token::BorrowedStr( $ord ),
+				Token::BorrowedStr("width"), Token::U8( $width ),
+				Token::BorrowedStr("head"), Token::U8(0),
+				Token::BorrowedStr("bits"), Token::U64(0),
+				Token::BorrowedStr("data"),
+			]
+		};
 		( d $elts:expr, $head:expr, $bits:expr, $ty:ident $( , $data:expr )* ) => {
 			&[
 				Token::Struct { name: "BitSeq", len: 3, },
@@ -399,7 +1308,7 @@ mod tests {
 	fn empty() {
 		let slice = BitSlice::<Msb0, u8>::empty();
 
-		assert_ser_tokens(&slice, bvtok![s 0, 0, 0, U8]);
+		assert_ser_tokens(&slice, bvtok![s Msb0, 8; 0, 0, 0, U8]);
 
 		#[cfg(feature = "alloc")]
 		assert_de_tokens(&bitvec![], bvtok![ d 0, 0, 0, U8 ]);
@@ -409,22 +1318,31 @@ mod tests {
 	fn small() {
 		let bits = 0b1111_1000u8.view_bits::<Msb0>();
 		let bits = &bits[1 .. 5];
-		assert_ser_tokens(&bits, bvtok![s 1, 1, 4, U8, 0b1111_1000]);
+		assert_ser_tokens(&bits, bvtok![s Msb0, 8; 1, 1, 4, U8, 0b1111_1000]);
 
 		let bits = 0b00001111_11111111u16.view_bits::<Lsb0>();
 		let bits = &bits[.. 12];
-		assert_ser_tokens(&bits, bvtok![s 1, 0, 12, U16, 0b00001111_11111111]);
+		assert_ser_tokens(
+			&bits,
+			bvtok![s Lsb0, 16; 1, 0, 12, U16, 0b00001111_11111111],
+		);
 
 		let bits = 0b11_11111111u32.view_bits::<LocalBits>();
 		let bits = &bits[.. 10];
-		assert_ser_tokens(&bits, bvtok![s 1, 0, 10, U32, 0x00_00_03_FF]);
+		assert_ser_tokens(
+			&bits,
+			bvtok![s LocalBits, 32; 1, 0, 10, U32, 0x00_00_03_FF],
+		);
 	}
 
 	#[test]
 	fn wide() {
 		let src: &[u8] = &[0, !0];
 		let bs = src.view_bits::<LocalBits>();
-		assert_ser_tokens(&(&bs[1 .. 15]), bvtok![s 2, 1, 14, U8, 0, !0]);
+		assert_ser_tokens(
+			&(&bs[1 .. 15]),
+			bvtok![s LocalBits, 8; 2, 1, 14, U8, 0, !0],
+		);
 	}
 
 	#[test]
@@ -439,14 +1357,56 @@ mod tests {
 		assert_de_tokens(&bb, bvtok![d 1, 0, 6, U8, 0b0110_1011]);
 	}
 
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn array() {
+		use serde_test::{
+			Compact,
+			Configure,
+			Readable,
+		};
+
+		let ba = bitarr![Msb0, u8; 1, 0, 1, 1, 0, 0, 1, 0, 1];
+		let bare = &[
+			Token::Tuple { len: 2 },
+			Token::U8(0b1011_0010),
+			Token::U8(0b1000_0000),
+			Token::TupleEnd,
+		];
+		assert_de_tokens(&ba.readable(), bare);
+		assert_de_tokens(&ba.compact(), bare);
+		assert_de_tokens(
+			&ba.readable(),
+			bvtok![d "Msb0", 8; 2, 0, 16, U8, 0b1011_0010, 0b1000_0000],
+		);
+		assert_de_tokens(
+			&ba.readable(),
+			bvtok![d "Lsb0", 16; 1, 0, 16, U16, 0x014D],
+		);
+		let scalar = BitArray::<Lsb0, u8>::new(3);
+		assert_de_tokens(&scalar.readable(), &[Token::U8(3)]);
+
+		assert_de_tokens_error::<Readable<BitArray<Msb0, [u8; 2]>>>(
+			bvtok![e "Msb0", 8],
+			"invalid length 0, expected a BitSeq data series of 16 bits",
+		);
+		assert_de_tokens_error::<Compact<BitArray<Msb0, [u8; 2]>>>(
+			&[Token::Struct {
+				name: "BitSeq",
+				len: 5,
+			}],
+			"invalid type: map, expected an array of length 2",
+		);
+	}
+
 	#[test]
 	#[cfg(feature = "alloc")]
 	fn ser() {
 		let bv = bitvec![Msb0, u8; 0, 1, 1, 0, 1, 0];
 		let bb = bv.clone().into_boxed_bitslice();
 
-		assert_ser_tokens(&bv, bvtok![s 1, 0, 6, U8, 0b0110_1000]);
-		assert_ser_tokens(&bb, bvtok![s 1, 0, 6, U8, 0b0110_1000]);
+		assert_ser_tokens(&bv, bvtok![s Msb0, 8; 1, 0, 6, U8, 0b0110_1000]);
+		assert_ser_tokens(&bb, bvtok![s Msb0, 8; 1, 0, 6, U8, 0b0110_1000]);
 	}
 
 	#[test]
@@ -501,11 +1461,129 @@ mod tests {
 					len: 1,
 				},
 				Token::BorrowedStr("garbage"),
-				Token::BorrowedStr("field"),
+			],
+			"unknown field `garbage`, expected one of `order`, `width`, `head`, \
+			 `bits`, `data`",
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn layouts() {
+		let bv = bitvec![Msb0, u8; 0, 1, 1, 0, 1, 0, 0, 1, 1];
+		assert_de_tokens(&bv, bvtok![d "Msb0", 8; 2, 0, 9, U8, 0x69, 0x80]);
+
+		//  Payloads written with another of this crate’s layouts are
+		//  transcoded.
+		let lsb = bv.iter().copied().collect::<BitVec<Lsb0, u16>>();
+		assert_de_tokens(&lsb, bvtok![d "Msb0", 8; 2, 0, 9, U8, 0x69, 0x80]);
+		assert_de_tokens(&bv, bvtok![d "Lsb0", 16; 1, 3, 9, U16, 0x0CB0]);
+		assert_de_tokens(
+			&bv,
+			bvtok![d "Msb0", 64; 2, 60, 9, U64, 6, 0x98 << 56],
+		);
+
+		let json = serde_json::to_string(&bv).unwrap();
+		let other = serde_json::from_str::<BitVec<Lsb0, u32>>(&json).unwrap();
+		assert_eq!(other, bv);
+		assert_eq!(serde_json::to_string(&other).unwrap(), format!(
+			"{{\"order\":\"Lsb0\",\"width\":32,\"head\":0,\"bits\":9,\
+			 \"data\":[{}]}}",
+			0b1_1001_0110,
+		));
+
+		assert_de_tokens_error::<BitVec<Msb0, u8>>(
+			bvtok![e "my::Order", 8],
+			"invalid value: string \"my::Order\", expected the bit-order \
+			 `Lsb0` or `Msb0`",
+		);
+		assert_de_tokens_error::<BitVec<Msb0, u8>>(
+			bvtok![e "Lsb0", 12],
+			"invalid value: integer `12`, expected an element width of 8, 16, \
+			 32, or 64 bits",
+		);
+		assert_de_tokens_error::<BitVec<Msb0, u8>>(
+			bvtok![d "Lsb0", 16; 0, 16, 0, U16],
+			"invalid value: integer `16`, expected a head-bit index less than \
+			 the serialized element width",
+		);
+		assert_de_tokens_error::<BitVec<Msb0, u8>>(
+			bvtok![d "Lsb0", 16; 0, 3, 200, U16],
+			"invalid length 0, expected a memory element holding the head-bit \
+			 index",
+		);
+		//  Formats that sort their map keys write `data` before the fields that
+		//  describe it.
+		let dense = bitvec![Lsb0, u16; 0, 1, 1, 0, 1, 0, 0, 1, 1];
+		assert_de_tokens(&dense, &[
+			Token::Struct {
+				name: "BitSeq",
+				len: 5,
+			},
+			Token::BorrowedStr("data"),
+			Token::Seq { len: Some(1) },
+			Token::U16(0x0196),
+			Token::SeqEnd,
+			Token::BorrowedStr("head"),
+			Token::U8(0),
+			Token::BorrowedStr("bits"),
+			Token::U64(9),
+			Token::StructEnd,
+		]);
+		assert_de_tokens::<BitVec<Msb0, u8>>(&bv, &[
+			Token::Struct {
+				name: "BitSeq",
+				len: 5,
+			},
+			Token::BorrowedStr("data"),
+			Token::Seq { len: Some(1) },
+			Token::U16(0x0CB0),
+			Token::SeqEnd,
+			Token::BorrowedStr("head"),
+			Token::U8(3),
+			Token::BorrowedStr("bits"),
+			Token::U64(9),
+			Token::BorrowedStr("order"),
+			Token::BorrowedStr("Lsb0"),
+			Token::BorrowedStr("width"),
+			Token::U8(16),
+			Token::StructEnd,
+		]);
+		assert_de_tokens_error::<BitVec<Msb0, u8>>(
+			&[
+				Token::Struct {
+					name: "BitSeq",
+					len: 5,
+				},
+				Token::BorrowedStr("data"),
+				Token::Seq { len: Some(1) },
+				Token::U16(0x0CB0),
+				Token::SeqEnd,
+				Token::BorrowedStr("head"),
+				Token::U8(3),
+				Token::BorrowedStr("bits"),
+				Token::U64(9),
 				Token::StructEnd,
 			],
-			"unknown field `garbage`, expected one of `head`, `bits`, `data`",
+			"invalid value: integer `3248`, expected a memory element of the \
+			 serialized width",
 		);
+
+		let value = serde_json::to_value(&bv).unwrap();
+		let lsb = serde_json::from_value::<BitVec<Lsb0, u16>>(value).unwrap();
+		assert_eq!(lsb, bv);
+
+		assert_de_tokens::<BitVec<Msb0, u8>>(&bv, &[
+			Token::Seq { len: Some(5) },
+			Token::BorrowedStr("Lsb0"),
+			Token::U8(16),
+			Token::U8(3),
+			Token::U64(9),
+			Token::Seq { len: Some(1) },
+			Token::U16(0x0CB0),
+			Token::SeqEnd,
+			Token::SeqEnd,
+		]);
 	}
 
 	#[test]
@@ -542,6 +1620,163 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn borrowed() {
+		use serde_test::{
+			assert_de_tokens,
+			assert_de_tokens_error,
+		};
+
+		let bits = &0b0110_1100u8.view_bits::<Msb0>()[1 .. 6];
+		assert_de_tokens(&bits, &[
+			Token::Struct {
+				name: "BitSeq",
+				len: 5,
+			},
+			Token::BorrowedStr("order"),
+			Token::BorrowedStr("Msb0"),
+			Token::BorrowedStr("width"),
+			Token::U8(8),
+			Token::BorrowedStr("head"),
+			Token::U8(1),
+			Token::BorrowedStr("bits"),
+			Token::U64(5),
+			Token::BorrowedStr("data"),
+			Token::BorrowedBytes(&[0b0110_1100]),
+			Token::StructEnd,
+		]);
+		assert_de_tokens(&bits, &[
+			Token::Seq { len: Some(3) },
+			Token::U8(1),
+			Token::U64(5),
+			Token::BorrowedBytes(&[0b0110_1100]),
+			Token::SeqEnd,
+		]);
+
+		assert_de_tokens_error::<&BitSlice<Lsb0, u8>>(
+			&[
+				Token::Seq { len: Some(5) },
+				Token::BorrowedStr("Msb0"),
+				Token::U8(8),
+			],
+			"invalid value: string \"Msb0\", expected Lsb0",
+		);
+		assert_de_tokens_error::<&BitSlice<Msb0, u8>>(
+			&[
+				Token::Seq { len: Some(5) },
+				Token::BorrowedStr("Msb0"),
+				Token::U8(16),
+			],
+			"invalid value: integer `16`, expected an element width of 8 bits",
+		);
+		assert_de_tokens_error::<&BitSlice<Msb0, u8>>(
+			&[
+				Token::Seq { len: Some(3) },
+				Token::U8(8),
+				Token::U64(0),
+				Token::BorrowedBytes(&[0]),
+			],
+			"invalid value: integer `8`, expected a head-bit index less than 8",
+		);
+		assert_de_tokens_error::<&BitSlice<Msb0, u8>>(
+			&[
+				Token::Seq { len: Some(3) },
+				Token::U8(3),
+				Token::U64(200),
+				Token::BorrowedBytes(&[]),
+			],
+			"invalid length 0, expected a memory element holding the head-bit \
+			 index",
+		);
+		assert_de_tokens_error::<&BitSlice<Msb0, u8>>(
+			&[
+				Token::Seq { len: Some(3) },
+				Token::U8(0),
+				Token::U64(8),
+				Token::Bytes(&[0]),
+			],
+			"invalid type: byte array, expected a borrowed byte array",
+		);
+
+		//  `bincode` writes `u8` sequences in the same way as byte strings.
+		let bytes = bincode::serialize(bits).unwrap();
+		let out = bincode::deserialize::<&BitSlice<Msb0, u8>>(&bytes).unwrap();
+		assert_eq!(out, bits);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn compressed() {
+		use crate::compressed::CompressedBitmap;
+
+		let set = [3, 5, 70_000, 70_001, 70_002]
+			.iter()
+			.copied()
+			.collect::<CompressedBitmap>();
+		let tokens = &[
+			Token::Seq { len: Some(2) },
+			Token::Tuple { len: 3 },
+			Token::U16(0),
+			Token::U8(0),
+			Token::Seq { len: Some(2) },
+			Token::U16(3),
+			Token::U16(5),
+			Token::SeqEnd,
+			Token::TupleEnd,
+			Token::Tuple { len: 3 },
+			Token::U16(1),
+			Token::U8(1),
+			Token::Seq { len: Some(1) },
+			Token::Tuple { len: 2 },
+			Token::U16(4464),
+			Token::U16(4466),
+			Token::TupleEnd,
+			Token::SeqEnd,
+			Token::TupleEnd,
+			Token::SeqEnd,
+		];
+		let mut runs = set.clone();
+		runs.optimize();
+		assert_ser_tokens(&runs, tokens);
+		assert_de_tokens(&set, tokens);
+
+		let mut dense = bitvec![0; 1 << 16];
+		dense.iter_mut().step_by(2).for_each(|mut bit| *bit = true);
+		let dense = CompressedBitmap::from_bitslice(&dense);
+		let json = serde_json::to_string(&dense).unwrap();
+		assert_eq!(
+			serde_json::from_str::<CompressedBitmap>(&json).unwrap(),
+			dense
+		);
+
+		assert_de_tokens_error::<CompressedBitmap>(
+			&[
+				Token::Seq { len: Some(1) },
+				Token::Tuple { len: 3 },
+				Token::U16(0),
+				Token::U8(0),
+				Token::Seq { len: Some(2) },
+				Token::U16(5),
+				Token::U16(3),
+				Token::SeqEnd,
+				Token::TupleEnd,
+				Token::SeqEnd,
+			],
+			"invalid value: array chunks must be strictly ascending, expected \
+			 a sequence of compressed bit-map chunks",
+		);
+		assert_de_tokens_error::<CompressedBitmap>(
+			&[
+				Token::Seq { len: Some(1) },
+				Token::Tuple { len: 3 },
+				Token::U16(0),
+				Token::U8(7),
+			],
+			"invalid value: integer `7`, expected a chunk kind of 0 (array), \
+			 1 (runs), or 2 (dense)",
+		);
+	}
+
 	#[test]
 	fn trait_impls() {
 		const _: fn() = || {
@@ -0,0 +1,766 @@
+/*! De/serialization of [`BitArray`] in the `BitSeq` format.
+
+[`BitArray`] normally serializes as a bare sequence of its memory elements, which
+cannot be read by [`BitVec`] or [`BitBox`]. It can only read their `BitSeq`
+format in human-readable protocols. The functions in this module de/serialize a
+[`BitArray`] in the `BitSeq` format instead, in any protocol, so that fixed-size
+and dynamically-sized bit-sequences can share a schema.
+
+[`serialize`] accepts any bit-slice, and [`deserialize`] produces a
+[`BitArray`]. Deserialization accepts the same payloads as [`BitVec`] does,
+including those written in another of this crate’s layouts, and fails if the
+`bits` field does not match the length of the array. The fields may arrive in
+any order: when `data` precedes the fields that describe it, as in formats that
+sort map keys, its elements are buffered until the map ends. Without the `alloc`
+feature there is no such buffer, and `order`, `width`, `head`, and `bits` must
+all precede `data`, as they do when written by this crate.
+
+These functions are designed to be used with the `#[serde(with)]` attribute, on
+fields of type [`BitArray`]:
+
+```rust
+use bitvec::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Registers {
+	#[serde(with = "bitvec::serdes::bitseq")]
+	flags: BitArray<Msb0, [u8; 2]>,
+}
+
+let regs = Registers { flags: BitArray::new([0xFF, 0xF0]) };
+let json = serde_json::to_string(&regs).unwrap();
+assert_eq!(
+	json,
+	r#"{"flags":{"order":"Msb0","width":8,"head":0,"bits":16,"data":[255,240]}}"#,
+);
+
+let copy: Registers = serde_json::from_str(&json).unwrap();
+assert_eq!(copy, regs);
+```
+
+# Examples
+
+```rust
+# #[cfg(feature = "alloc")] {
+use bitvec::prelude::*;
+use bitvec::serdes::bitseq;
+use serde_json::{Deserializer, Serializer};
+
+let array = bitarr![Msb0, u8; 1, 0, 1, 1, 0, 0, 1, 0];
+let mut json = Vec::new();
+bitseq::serialize(&array, &mut Serializer::new(&mut json)).unwrap();
+
+let bv: BitVec<Lsb0, u16> = serde_json::from_slice(&json).unwrap();
+assert_eq!(bv, array);
+
+let mut de = Deserializer::from_slice(&json);
+let copy: BitArray<Msb0, [u8; 1]> = bitseq::deserialize(&mut de).unwrap();
+assert_eq!(copy, array);
+# }
+```
+
+[`BitArray`]: crate::array::BitArray
+[`BitBox`]: crate::boxed::BitBox
+[`BitVec`]: crate::vec::BitVec
+[`deserialize`]: self::deserialize
+[`serialize`]: self::serialize
+!*/
+
+use super::{
+	find_layout,
+	Field,
+	order_name,
+	ExpectOrder,
+	Layout,
+	FIELDS,
+};
+
+use crate::{
+	array::BitArray,
+	mem::BitMemory,
+	order::{
+		BitOrder,
+		Lsb0,
+		Msb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	view::BitView,
+};
+
+use core::{
+	cmp,
+	convert::TryFrom,
+	fmt::{
+		self,
+		Formatter,
+	},
+	marker::PhantomData,
+};
+
+use serde::{
+	de::{
+		self,
+		Deserialize,
+		DeserializeSeed,
+		Deserializer,
+		MapAccess,
+		SeqAccess,
+		Unexpected,
+		Visitor,
+	},
+	ser::{
+		Serialize,
+		Serializer,
+	},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Serializes a bit-slice, such as the contents of a [`BitArray`], in the
+/// `BitSeq` format.
+///
+/// [`BitArray`]: crate::array::BitArray
+pub fn serialize<O, T, S>(
+	bits: &BitSlice<O, T>,
+	serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+	O: BitOrder,
+	T: BitStore,
+	T::Mem: Serialize,
+	S: Serializer,
+{
+	bits.serialize(serializer)
+}
+
+/// Deserializes a [`BitArray`] from the `BitSeq` format.
+///
+/// # Returns
+///
+/// The deserialized array, or an error if the payload is malformed or its
+/// `bits` field is not the length of the array.
+///
+/// [`BitArray`]: crate::array::BitArray
+pub fn deserialize<'de, D, O, V>(
+	deserializer: D,
+) -> Result<BitArray<O, V>, D::Error>
+where
+	D: Deserializer<'de>,
+	O: BitOrder,
+	V: BitView,
+	<V::Store as BitStore>::Mem: Deserialize<'de>,
+{
+	deserializer.deserialize_struct(
+		"BitSeq",
+		FIELDS,
+		ArrayVisitor::<O, V>::THIS,
+	)
+}
+
+/// Aid for deserializing a `BitSeq` into a [`BitArray`].
+///
+/// [`BitArray`]: crate::array::BitArray
+pub(super) struct ArrayVisitor<O, V>(PhantomData<BitArray<O, V>>)
+where
+	O: BitOrder,
+	V: BitView;
+
+impl<O, V> ArrayVisitor<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	pub(super) const THIS: Self = Self(PhantomData);
+
+	/// Determines how to read the `data` field, using the element type of the
+	/// array.
+	fn layout<E>(order: Option<&str>, width: Option<u8>) -> Result<Layout, E>
+	where E: de::Error {
+		find_layout::<O, <V::Store as BitStore>::Mem, E>(order, width)
+	}
+
+	/// Checks the `bits` field against the length of the array.
+	fn check_len<'de, E>(&self, bits: u64) -> Result<(), E>
+	where
+		Self: Visitor<'de>,
+		E: de::Error,
+	{
+		if bits == V::const_bits() as u64 {
+			Ok(())
+		}
+		else {
+			Err(de::Error::invalid_length(bits as usize, self))
+		}
+	}
+}
+
+impl<'de, O, V> Visitor<'de> for ArrayVisitor<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+	<V::Store as BitStore>::Mem: Deserialize<'de>,
+{
+	type Value = BitArray<O, V>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "a BitSeq data series of {} bits", V::const_bits())
+	}
+
+	/// Visit a sequence of anonymous data elements, in the same order as the
+	/// owning deserializers accept. As there, the older layout is only
+	/// recognized in formats that report a length of three or fewer elements.
+	fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+	where A: SeqAccess<'de> {
+		let legacy = matches!(seq.size_hint(), Some(len) if len <= 3);
+		let (layout, skip) = if legacy {
+			(Layout::Native, 0)
+		}
+		else {
+			let order = seq
+				.next_element_seed(OrderSeed::<O>(PhantomData))?
+				.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+			let width = seq
+				.next_element::<u8>()?
+				.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+			(Self::layout(Some(order), Some(width))?, 2)
+		};
+
+		let head = seq
+			.next_element::<u8>()?
+			.ok_or_else(|| de::Error::invalid_length(skip, &self))?;
+		let bits = seq
+			.next_element::<u64>()?
+			.ok_or_else(|| de::Error::invalid_length(skip + 1, &self))?;
+		self.check_len(bits)?;
+
+		let mut out = BitArray::<O, V>::zeroed();
+		seq.next_element_seed(DataSeed::new(&mut out, layout, head))?
+			.ok_or_else(|| de::Error::invalid_length(skip + 2, &self))?;
+		Ok(out)
+	}
+
+	/// Visit a map of named data elements, with the same keys as the owning
+	/// deserializers accept, in any order.
+	fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+	where A: MapAccess<'de> {
+		let mut order: Option<&'static str> = None;
+		let mut width: Option<u8> = None;
+		let mut head: Option<u8> = None;
+		let mut bits: Option<u64> = None;
+		let mut out: Option<BitArray<O, V>> = None;
+		//  Data elements that arrived before the fields describing them.
+		#[cfg(feature = "alloc")]
+		let mut early: Option<Vec<u64>> = None;
+
+		while let Some(key) = map.next_key()? {
+			#[cfg(not(feature = "alloc"))]
+			if out.is_some() {
+				let _ = map.next_value::<de::IgnoredAny>();
+				return Err(de::Error::custom(
+					"the `data` field must be the last field of a BitSeq",
+				));
+			}
+			match key {
+				Field::Order => {
+					let seed = OrderSeed::<O>(PhantomData);
+					if order.replace(map.next_value_seed(seed)?).is_some() {
+						return Err(de::Error::duplicate_field("order"));
+					}
+				},
+				Field::Width => {
+					if width.replace(map.next_value()?).is_some() {
+						return Err(de::Error::duplicate_field("width"));
+					}
+				},
+				Field::Head => {
+					if head.replace(map.next_value()?).is_some() {
+						return Err(de::Error::duplicate_field("head"));
+					}
+				},
+				Field::Bits => {
+					if bits.replace(map.next_value()?).is_some() {
+						return Err(de::Error::duplicate_field("bits"));
+					}
+				},
+				Field::Data => {
+					#[cfg(feature = "alloc")]
+					{
+						if out.is_some() || early.is_some() {
+							return Err(de::Error::duplicate_field("data"));
+						}
+						if order.is_none()
+							|| width.is_none() || head.is_none()
+							|| bits.is_none()
+						{
+							//  A head index is less than the element width, and
+							//  elements are at least eight bits wide, so no live
+							//  bit lies past this many elements.
+							let seed = WordsSeed {
+								limit: V::const_bits() / 8 + 1,
+							};
+							early = Some(map.next_value_seed(seed)?);
+							continue;
+						}
+					}
+					let layout = Self::layout(order, width)?;
+					let head =
+						head.ok_or_else(|| de::Error::missing_field("head"))?;
+					let bits =
+						bits.ok_or_else(|| de::Error::missing_field("bits"))?;
+					self.check_len(bits)?;
+					let mut array = BitArray::<O, V>::zeroed();
+					let seed = DataSeed::new(&mut array, layout, head);
+					map.next_value_seed(seed)?;
+					out = Some(array);
+				},
+			}
+		}
+
+		#[cfg(feature = "alloc")]
+		if let Some(words) = early {
+			let layout = Self::layout(order, width)?;
+			let head = head.ok_or_else(|| de::Error::missing_field("head"))?;
+			let bits = bits.ok_or_else(|| de::Error::missing_field("bits"))?;
+			self.check_len(bits)?;
+			let mut array = BitArray::<O, V>::zeroed();
+			DataSeed::new(&mut array, layout, head).replay(&words)?;
+			return Ok(array);
+		}
+		out.ok_or_else(|| de::Error::missing_field("data"))
+	}
+}
+
+/// Buffers the `data` field of a `BitSeq` that arrives before the fields that
+/// describe it.
+///
+/// Each element is widened to `u64`. Elements past `limit` cannot hold live
+/// bits of the destination array, and are discarded.
+#[cfg(feature = "alloc")]
+struct WordsSeed {
+	/// The number of elements to keep.
+	limit: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> DeserializeSeed<'de> for WordsSeed {
+	type Value = Vec<u64>;
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_seq(self)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> Visitor<'de> for WordsSeed {
+	type Value = Vec<u64>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("a sequence of memory elements")
+	}
+
+	fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+	where A: SeqAccess<'de> {
+		let hint = cmp::min(seq.size_hint().unwrap_or(0), self.limit);
+		let mut words = Vec::with_capacity(hint);
+		while words.len() < self.limit {
+			match seq.next_element::<u64>()? {
+				Some(word) => words.push(word),
+				None => return Ok(words),
+			}
+		}
+		while seq.next_element::<de::IgnoredAny>()?.is_some() {}
+		Ok(words)
+	}
+}
+
+/// Deserializes the `order` field of a `BitSeq` without allocating.
+///
+/// The field is only ever compared against the name of `O`, `Lsb0`, and `Msb0`,
+/// so it is replaced by whichever of those it matches.
+struct OrderSeed<O>(PhantomData<O>)
+where O: BitOrder;
+
+impl<'de, O> DeserializeSeed<'de> for OrderSeed<O>
+where O: BitOrder
+{
+	type Value = &'static str;
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_str(self)
+	}
+}
+
+impl<'de, O> Visitor<'de> for OrderSeed<O>
+where O: BitOrder
+{
+	type Value = &'static str;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("the name of a bit-order")
+	}
+
+	fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+	where E: de::Error {
+		let name = order_name::<O>();
+		match value {
+			v if v == name => Ok(name),
+			"Lsb0" => Ok("Lsb0"),
+			"Msb0" => Ok("Msb0"),
+			_ => Err(de::Error::invalid_value(
+				Unexpected::Str(value),
+				&ExpectOrder::<O>(PhantomData),
+			)),
+		}
+	}
+}
+
+/// Deserializes the `data` field of a `BitSeq` directly into an array.
+struct DataSeed<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The bits of the destination array.
+	dest: &'a mut BitSlice<O, T>,
+	/// The layout in which the data was written.
+	layout: Layout,
+	/// The index of the first live bit in the first data element.
+	head: u8,
+}
+
+impl<'a, O, T> DataSeed<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn new<V>(array: &'a mut BitArray<O, V>, layout: Layout, head: u8) -> Self
+	where V: BitView<Store = T> {
+		Self {
+			dest: array.as_mut_bitslice(),
+			layout,
+			head,
+		}
+	}
+
+	/// The bit width of the serialized data elements.
+	fn width(&self) -> u8 {
+		match self.layout {
+			Layout::Native => T::Mem::BITS,
+			Layout::Foreign { width, .. } => width,
+		}
+	}
+
+	/// Checks that the head index lies within the first data element.
+	fn check_head<E>(&self) -> Result<(), E>
+	where E: de::Error {
+		if self.head < self.width() {
+			Ok(())
+		}
+		else {
+			Err(de::Error::invalid_value(
+				Unexpected::Unsigned(self.head as u64),
+				&"a head-bit index less than the serialized element width",
+			))
+		}
+	}
+
+	/// Checks that the data elements, ending at bit index `start`, covered
+	/// every live bit.
+	fn check_end<E>(&self, start: usize) -> Result<(), E>
+	where E: de::Error {
+		if start < self.head as usize + self.dest.len() {
+			Err(de::Error::invalid_length(
+				start / self.width() as usize,
+				&"enough memory elements to hold every bit",
+			))
+		}
+		else {
+			Ok(())
+		}
+	}
+
+	/// Copies the live bits out of one data element.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `start`: The bit index, counted from the start of the data, of the
+	///   first bit in `elem`.
+	/// - `elem`: The bits of one data element.
+	fn take<O2, T2>(&mut self, start: usize, elem: &BitSlice<O2, T2>)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let head = self.head as usize;
+		let end = head + self.dest.len();
+		let lo = cmp::max(start, head);
+		let hi = cmp::min(start + elem.len(), end);
+		if lo < hi {
+			self.dest[lo - head .. hi - head]
+				.clone_from_bitslice(&elem[lo - start .. hi - start]);
+		}
+	}
+
+	/// Copies the live bits out of one data element, widened to `u64`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `start`: The bit index, counted from the start of the data, of the
+	///   first bit in `word`.
+	/// - `word`: One data element, widened to `u64`.
+	///
+	/// # Returns
+	///
+	/// An error if `word` does not fit in a native memory element.
+	fn take_word<E>(&mut self, start: usize, word: u64) -> Result<(), E>
+	where E: de::Error {
+		match self.layout {
+			Layout::Native => {
+				let elem = T::Mem::try_from(word).map_err(|_| {
+					de::Error::invalid_value(
+						Unexpected::Unsigned(word),
+						&"a memory element of the serialized width",
+					)
+				})?;
+				self.take(start, elem.view_bits::<O>());
+			},
+			Layout::Foreign { msb0: true, width } => {
+				let width = width as usize;
+				let word = word << (64 - width);
+				self.take(start, &word.view_bits::<Msb0>()[.. width]);
+			},
+			Layout::Foreign { msb0: false, width } => {
+				let width = width as usize;
+				self.take(start, &word.view_bits::<Lsb0>()[.. width]);
+			},
+		}
+		Ok(())
+	}
+
+	/// Copies the live bits out of data elements that were buffered before
+	/// their layout was known.
+	#[cfg(feature = "alloc")]
+	fn replay<E>(mut self, words: &[u64]) -> Result<(), E>
+	where E: de::Error {
+		self.check_head()?;
+		let width = self.width() as usize;
+		let mut start = 0;
+		for &word in words {
+			self.take_word(start, word)?;
+			start += width;
+		}
+		self.check_end(start)
+	}
+}
+
+impl<'de, 'a, O, T> DeserializeSeed<'de> for DataSeed<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	T::Mem: Deserialize<'de>,
+{
+	type Value = ();
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_seq(self)
+	}
+}
+
+impl<'de, 'a, O, T> Visitor<'de> for DataSeed<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	T::Mem: Deserialize<'de>,
+{
+	type Value = ();
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("a sequence of memory elements")
+	}
+
+	fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+	where A: SeqAccess<'de> {
+		self.check_head()?;
+		let width = self.width() as usize;
+
+		let mut start = 0;
+		loop {
+			match self.layout {
+				Layout::Native => match seq.next_element::<T::Mem>()? {
+					Some(elem) => self.take(start, elem.view_bits::<O>()),
+					None => break,
+				},
+				Layout::Foreign { .. } => {
+					let word = match width {
+						8 => seq.next_element::<u8>()?.map(u64::from),
+						16 => seq.next_element::<u16>()?.map(u64::from),
+						32 => seq.next_element::<u32>()?.map(u64::from),
+						_ => seq.next_element::<u64>()?,
+					};
+					match word {
+						Some(word) => self.take_word(start, word)?,
+						None => break,
+					}
+				},
+			}
+			start += width;
+		}
+		self.check_end(start)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::prelude::*;
+
+	use serde::{
+		Deserialize,
+		Serialize,
+	};
+
+	use serde_test::{
+		assert_de_tokens,
+		assert_de_tokens_error,
+		assert_ser_tokens,
+		Token,
+	};
+
+	/// A field using the `BitSeq` representation.
+	#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+	struct Flags(
+		#[serde(with = "crate::serdes::bitseq")] BitArray<Msb0, [u8; 2]>,
+	);
+
+	const FLAGS: Token = Token::NewtypeStruct { name: "Flags" };
+
+	#[test]
+	fn round_trip() {
+		let flags = Flags(bitarr![Msb0, u8; 1, 0, 1, 1, 0, 0, 1, 0, 1]);
+		let tokens = &[
+			FLAGS,
+			Token::Struct {
+				name: "BitSeq",
+				len: 5,
+			},
+			Token::Str("order"),
+			Token::Str("Msb0"),
+			Token::Str("width"),
+			Token::U8(8),
+			Token::Str("head"),
+			Token::U8(0),
+			Token::Str("bits"),
+			Token::U64(16),
+			Token::Str("data"),
+			Token::Seq { len: Some(2) },
+			Token::U8(0b1011_0010),
+			Token::U8(0b1000_0000),
+			Token::SeqEnd,
+			Token::StructEnd,
+		];
+		assert_ser_tokens(&flags, tokens);
+		assert_de_tokens(&flags, tokens);
+
+		//  A shifted, foreign-layout payload is realigned into the array.
+		assert_de_tokens(&flags, &[
+			FLAGS,
+			Token::Seq { len: Some(5) },
+			Token::BorrowedStr("Lsb0"),
+			Token::U8(16),
+			Token::U8(3),
+			Token::U64(16),
+			Token::Seq { len: Some(2) },
+			Token::U16(0b1010_0110_1000),
+			Token::U16(0b1000),
+			Token::SeqEnd,
+			Token::SeqEnd,
+		]);
+	}
+
+	#[test]
+	fn errors() {
+		assert_de_tokens_error::<Flags>(
+			&[
+				FLAGS,
+				Token::Seq { len: Some(5) },
+				Token::BorrowedStr("Msb0"),
+				Token::U8(8),
+				Token::U8(0),
+				Token::U64(9),
+			],
+			"invalid length 9, expected a BitSeq data series of 16 bits",
+		);
+		assert_de_tokens_error::<Flags>(
+			&[
+				FLAGS,
+				Token::Seq { len: Some(5) },
+				Token::BorrowedStr("Msb0"),
+				Token::U8(8),
+				Token::U8(1),
+				Token::U64(16),
+				Token::Seq { len: Some(2) },
+				Token::U8(0),
+				Token::U8(0),
+				Token::SeqEnd,
+			],
+			"invalid length 2, expected enough memory elements to hold every \
+			 bit",
+		);
+		assert_de_tokens_error::<Flags>(
+			&[
+				FLAGS,
+				Token::Map { len: None },
+				Token::BorrowedStr("head"),
+				Token::U8(0),
+				Token::BorrowedStr("data"),
+				Token::Seq { len: Some(2) },
+				Token::U8(0),
+				Token::U8(0),
+				Token::SeqEnd,
+				Token::MapEnd,
+			],
+			"missing field `bits`",
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn data_first() {
+		//  Formats that sort their map keys write `data` before the fields that
+		//  describe it.
+		let flags = Flags(bitarr![Msb0, u8; 1, 0, 1, 1, 0, 0, 1, 0, 1]);
+		let value = serde_json::to_value(&flags).unwrap();
+		let copy: Flags = serde_json::from_value(value.clone()).unwrap();
+		assert_eq!(copy, flags);
+		let array: BitArray<Msb0, [u8; 2]> =
+			serde_json::from_value(value).unwrap();
+		assert_eq!(array, flags.0);
+
+		assert_de_tokens(&flags, &[
+			FLAGS,
+			Token::Map { len: Some(5) },
+			Token::BorrowedStr("data"),
+			Token::Seq { len: Some(3) },
+			Token::U16(0b1010_0110_1000),
+			Token::U16(0b1000),
+			Token::U16(0xFFFF),
+			Token::SeqEnd,
+			Token::BorrowedStr("bits"),
+			Token::U64(16),
+			Token::BorrowedStr("head"),
+			Token::U8(3),
+			Token::BorrowedStr("order"),
+			Token::BorrowedStr("Lsb0"),
+			Token::BorrowedStr("width"),
+			Token::U8(16),
+			Token::MapEnd,
+		]);
+	}
+}
@@ -0,0 +1,245 @@
+/*! Compact de/serialization of `u8`-backed bit-sequences as byte strings.
+
+The `BitSeq` format writes each memory element as a separate number, which most
+formats encode in several times the size of the raw byte. The functions in this
+module write a bit-slice of `u8` elements with [`Serializer::serialize_bytes`]
+instead, which formats such as bincode and CBOR store as a length-prefixed byte
+string.
+
+The byte string holds one byte counting the unused bits at the back of the last
+data byte, followed by the data bytes. The bits are realigned so that the first
+bit of the slice is at the front of the first data byte, and the unused bits
+are cleared.
+
+These functions are designed to be used with the `#[serde(with)]` attribute,
+on fields of type [`BitVec<O, u8>`] or [`BitBox<O, u8>`]:
+
+```rust
+use bitvec::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Packet {
+	#[serde(with = "bitvec::serdes::bytes")]
+	flags: BitVec<Msb0, u8>,
+}
+
+let packet = Packet { flags: bitvec![Msb0, u8; 1, 0, 1] };
+let json = serde_json::to_string(&packet).unwrap();
+assert_eq!(json, r#"{"flags":[5,160]}"#);
+
+let copy: Packet = serde_json::from_str(&json).unwrap();
+assert_eq!(copy, packet);
+```
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::serdes::bytes;
+use serde_json::{json, value::Serializer};
+
+let bits = bits![Msb0, u8; 1, 0, 1, 1, 0, 0, 1, 0, 1];
+let value = bytes::serialize(bits, Serializer).unwrap();
+assert_eq!(value, json!([7, 0b1011_0010, 0b1000_0000]));
+
+let bv: BitVec<Msb0, u8> = bytes::deserialize(value).unwrap();
+assert_eq!(bv, bits);
+```
+
+[`BitBox<O, u8>`]: crate::boxed::BitBox
+[`BitVec<O, u8>`]: crate::vec::BitVec
+[`Serializer::serialize_bytes`]: serde::ser::Serializer::serialize_bytes
+!*/
+
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	vec::BitVec,
+};
+
+use alloc::vec::Vec;
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Formatter,
+	},
+	marker::PhantomData,
+};
+
+use serde::{
+	de::{
+		self,
+		Deserializer,
+		SeqAccess,
+		Unexpected,
+		Visitor,
+	},
+	ser::Serializer,
+};
+
+/// Serializes a bit-slice as a byte string.
+///
+/// # Parameters
+///
+/// - `bits`: The bit-slice to serialize.
+/// - `serializer`: The destination format.
+///
+/// # Returns
+///
+/// The result of [`Serializer::serialize_bytes`] on the padding count and the
+/// realigned data bytes.
+///
+/// [`Serializer::serialize_bytes`]: serde::ser::Serializer::serialize_bytes
+pub fn serialize<O, S>(
+	bits: &BitSlice<O, u8>,
+	serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+	O: BitOrder,
+	S: Serializer,
+{
+	let mut data = BitVec::<O, u8>::repeat(false, bits.len());
+	data.copy_from_bitslice(bits);
+	let data = data.as_slice();
+
+	let mut out = Vec::with_capacity(data.len() + 1);
+	out.push((data.len() * 8 - bits.len()) as u8);
+	out.extend_from_slice(data);
+	serializer.serialize_bytes(&out)
+}
+
+/// Deserializes a bit-sequence from a byte string written by [`serialize`].
+///
+/// Formats that have no byte-string type, and write byte strings as sequences
+/// of integers, are also accepted.
+///
+/// # Type Parameters
+///
+/// - `B`: The bit-sequence type to produce, such as [`BitVec<O, u8>`] or
+///   [`BitBox<O, u8>`].
+///
+/// # Returns
+///
+/// The deserialized bit-sequence, or an error if the byte string is empty, or
+/// its padding count is not less than eight.
+///
+/// [`BitBox<O, u8>`]: crate::boxed::BitBox
+/// [`BitVec<O, u8>`]: crate::vec::BitVec
+/// [`serialize`]: self::serialize
+pub fn deserialize<'de, D, O, B>(deserializer: D) -> Result<B, D::Error>
+where
+	D: Deserializer<'de>,
+	O: BitOrder,
+	B: From<BitVec<O, u8>>,
+{
+	deserializer
+		.deserialize_byte_buf(BytesVisitor::<O>(PhantomData))
+		.map(B::from)
+}
+
+/// Aid for deserializing a byte string into a [`BitVec`].
+///
+/// [`BitVec`]: crate::vec::BitVec
+struct BytesVisitor<O>(PhantomData<O>)
+where O: BitOrder;
+
+impl<'de, O> Visitor<'de> for BytesVisitor<O>
+where O: BitOrder
+{
+	type Value = BitVec<O, u8>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("a byte string holding a padding count and bit data")
+	}
+
+	fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+	where E: de::Error {
+		let (pad, data) = value
+			.split_first()
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+		if *pad >= 8 || (data.is_empty() && *pad != 0) {
+			return Err(de::Error::invalid_value(
+				Unexpected::Unsigned(*pad as u64),
+				&"a padding count less than 8, and 0 when there is no data",
+			));
+		}
+		let mut out = BitVec::from_vec(data.to_vec());
+		out.truncate(data.len() * 8 - *pad as usize);
+		Ok(out)
+	}
+
+	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		//  Do not trust the length hint with a large allocation.
+		let hint = cmp::min(seq.size_hint().unwrap_or(0), 4096);
+		let mut bytes = Vec::with_capacity(hint);
+		while let Some(byte) = seq.next_element::<u8>()? {
+			bytes.push(byte);
+		}
+		self.visit_bytes(&bytes)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::prelude::*;
+
+	use serde::{
+		Deserialize,
+		Serialize,
+	};
+
+	use serde_test::{
+		assert_de_tokens,
+		assert_de_tokens_error,
+		assert_tokens,
+		Token,
+	};
+
+	/// A field using the byte-string representation.
+	#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+	struct Flags(#[serde(with = "crate::serdes::bytes")] BitBox<Lsb0, u8>);
+
+	const FLAGS: Token = Token::NewtypeStruct { name: "Flags" };
+
+	#[test]
+	fn round_trip() {
+		let data = [0xA5u8, 0x3C];
+		let flags = Flags(data.view_bits::<Lsb0>()[3 .. 13].into());
+		assert_tokens(&flags, &[FLAGS, Token::Bytes(&[6, 0x94, 0x03])]);
+		assert_de_tokens(&flags, &[
+			FLAGS,
+			Token::Seq { len: Some(3) },
+			Token::U8(6),
+			Token::U8(0x94),
+			Token::U8(0x03),
+			Token::SeqEnd,
+		]);
+
+		let flags = Flags(data.view_bits::<Lsb0>().into());
+		assert_tokens(&flags, &[FLAGS, Token::Bytes(&[0, 0xA5, 0x3C])]);
+		assert_tokens(&Flags(BitBox::default()), &[FLAGS, Token::Bytes(&[0])]);
+	}
+
+	#[test]
+	fn errors() {
+		assert_de_tokens_error::<Flags>(
+			&[FLAGS, Token::Bytes(&[])],
+			"invalid length 0, expected a byte string holding a padding count \
+			 and bit data",
+		);
+		for bytes in &[&[8u8, 0][..], &[3]] {
+			assert_de_tokens_error::<Flags>(
+				&[FLAGS, Token::Bytes(*bytes)],
+				&format!(
+					"invalid value: integer `{}`, expected a padding count less \
+					 than 8, and 0 when there is no data",
+					bytes[0],
+				),
+			);
+		}
+	}
+}
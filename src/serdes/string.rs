@@ -0,0 +1,194 @@
+/*! Human-readable de/serialization of bit-sequences as strings of digits.
+
+The `BitSeq` format records the memory layout of a bit-slice, which makes it
+hard to read or to write by hand. The functions in this module write a
+bit-slice with [`Serializer::serialize_str`] as a string of `0` and `1`
+characters, one per bit, in index order. The string does not depend on the
+ordering or element type of the bit-slice, so it can be read into any other.
+
+These functions are designed to be used with the `#[serde(with)]` attribute,
+on fields of type [`BitVec`] or [`BitBox`], in human-readable formats such as
+JSON, TOML, or YAML:
+
+```rust
+use bitvec::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Config {
+	#[serde(with = "bitvec::serdes::string")]
+	mask: BitVec,
+}
+
+let config = Config { mask: bitvec![1, 0, 1, 1] };
+let json = serde_json::to_string(&config).unwrap();
+assert_eq!(json, r#"{"mask":"1011"}"#);
+
+let copy: Config = serde_json::from_str(&json).unwrap();
+assert_eq!(copy, config);
+```
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::serdes::string;
+use serde_json::{json, value::Serializer};
+
+let bits = bits![Lsb0, u16; 1, 0, 1, 1, 0];
+let value = string::serialize(bits, Serializer).unwrap();
+assert_eq!(value, json!("10110"));
+
+let bv: BitVec<Msb0, u8> = string::deserialize(value).unwrap();
+assert_eq!(bv, bits);
+```
+
+[`BitBox`]: crate::boxed::BitBox
+[`BitVec`]: crate::vec::BitVec
+[`Serializer::serialize_str`]: serde::ser::Serializer::serialize_str
+!*/
+
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use alloc::{
+	format,
+	string::String,
+};
+
+use core::{
+	fmt::{
+		self,
+		Formatter,
+	},
+	marker::PhantomData,
+};
+
+use serde::{
+	de::{
+		self,
+		Deserializer,
+		Unexpected,
+		Visitor,
+	},
+	ser::Serializer,
+};
+
+/// Serializes a bit-slice as a string of `0` and `1` digits.
+pub fn serialize<O, T, S>(
+	bits: &BitSlice<O, T>,
+	serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+	O: BitOrder,
+	T: BitStore,
+	S: Serializer,
+{
+	let text = bits
+		.iter()
+		.map(|bit| if *bit { '1' } else { '0' })
+		.collect::<String>();
+	serializer.serialize_str(&text)
+}
+
+/// Deserializes a bit-sequence from a string of `0` and `1` digits.
+///
+/// # Type Parameters
+///
+/// - `B`: The bit-sequence type to produce, such as [`BitVec`] or
+///   [`BitBox`].
+///
+/// # Returns
+///
+/// The deserialized bit-sequence, or an error naming the first character that
+/// is not a binary digit, and its position.
+///
+/// [`BitBox`]: crate::boxed::BitBox
+/// [`BitVec`]: crate::vec::BitVec
+pub fn deserialize<'de, D, O, T, B>(deserializer: D) -> Result<B, D::Error>
+where
+	D: Deserializer<'de>,
+	O: BitOrder,
+	T: BitStore,
+	B: From<BitVec<O, T>>,
+{
+	deserializer
+		.deserialize_str(StrVisitor::<O, T>(PhantomData))
+		.map(B::from)
+}
+
+/// Aid for deserializing a string of digits into a [`BitVec`].
+///
+/// [`BitVec`]: crate::vec::BitVec
+struct StrVisitor<O, T>(PhantomData<BitVec<O, T>>)
+where
+	O: BitOrder,
+	T: BitStore;
+
+impl<'de, O, T> Visitor<'de> for StrVisitor<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Value = BitVec<O, T>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("a string of binary digits")
+	}
+
+	fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+	where E: de::Error {
+		let mut out = BitVec::with_capacity(value.len());
+		for (idx, digit) in value.char_indices() {
+			match digit {
+				'0' => out.push(false),
+				'1' => out.push(true),
+				_ => {
+					return Err(de::Error::invalid_value(
+						Unexpected::Char(digit),
+						&format!("a binary digit at byte {}", idx).as_str(),
+					));
+				},
+			}
+		}
+		Ok(out)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::prelude::*;
+
+	use serde::{
+		Deserialize,
+		Serialize,
+	};
+
+	use serde_test::{
+		assert_de_tokens_error,
+		assert_tokens,
+		Token,
+	};
+
+	/// A field using the string representation.
+	#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+	struct Mask(#[serde(with = "crate::serdes::string")] BitBox<Msb0, u32>);
+
+	const MASK: Token = Token::NewtypeStruct { name: "Mask" };
+
+	#[test]
+	fn round_trip() {
+		let mask = Mask(bits![Msb0, u32; 0, 1, 1, 0, 1].into());
+		assert_tokens(&mask, &[MASK, Token::Str("01101")]);
+		assert_tokens(&Mask(BitBox::default()), &[MASK, Token::Str("")]);
+
+		assert_de_tokens_error::<Mask>(
+			&[MASK, Token::Str("0110x1")],
+			"invalid value: character `x`, expected a binary digit at byte 4",
+		);
+	}
+}
@@ -0,0 +1,906 @@
+/*! Sets of small integers, stored as bit-maps.
+
+This module provides two collections which treat a bit-sequence as a set of
+`usize` members, where the presence of `n` in the set is recorded by setting the
+bit at index `n`:
+
+- [`BitSet`] stores its members in a [`BitVec`], and grows as needed to hold
+  new members.
+- [`BitArraySet`] stores its members in a [`BitArray`], and can only hold
+  members that are less than its fixed capacity. It does not require an
+  allocator.
+
+The set-algebra operations work a memory element at a time, and do not walk
+individual bits. Member iteration uses [`BitSlice::iter_ones`].
+
+[`BitArray`]: crate::array::BitArray
+[`BitArraySet`]: self::BitArraySet
+[`BitSet`]: self::BitSet
+[`BitSlice::iter_ones`]: crate::slice::BitSlice::iter_ones
+[`BitVec`]: crate::vec::BitVec
+!*/
+
+use crate::{
+	array::BitArray,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::{
+		BitSlice,
+		IterOnes,
+	},
+	store::BitStore,
+	view::BitView,
+};
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::FromIterator,
+};
+
+use funty::IsInteger;
+
+#[cfg(feature = "alloc")]
+use crate::{
+	mem::BitMemory,
+	vec::BitVec,
+};
+
+/** A growable set of small integers.
+
+The set is stored as a [`BitVec`] whose length is always a whole number of `T`
+elements, so that the set operations can work on full elements. Its memory use
+is proportional to its largest member, not to its member count.
+
+# Type Parameters
+
+- `O`: The ordering of bits within the storage elements.
+- `T`: The storage element type.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::set::BitSet;
+
+let mut primes: BitSet = [2, 3, 5, 7].iter().copied().collect();
+assert!(primes.insert(11));
+assert!(!primes.insert(3));
+assert!(primes.contains(7));
+assert!(!primes.contains(9));
+
+let odds: BitSet = (1 .. 12).step_by(2).collect();
+let odd_primes = primes.intersection(&odds);
+assert_eq!(odd_primes.iter().collect::<Vec<_>>(), [3, 5, 7, 11]);
+assert!(odd_primes.is_subset(&primes));
+assert_eq!(primes.difference(&odds).len(), 1);
+```
+
+[`BitVec`]: crate::vec::BitVec
+**/
+#[cfg(feature = "alloc")]
+pub struct BitSet<O = Lsb0, T = usize>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The member bit-map. Its length is always a multiple of `T`’s width,
+	/// and it always begins at the front edge of its first element.
+	bits: BitVec<O, T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Constructs a new, empty, set.
+	///
+	/// This does not allocate until a member is inserted.
+	pub fn new() -> Self {
+		Self {
+			bits: BitVec::new(),
+		}
+	}
+
+	/// Constructs a new, empty, set that can hold members less than
+	/// `capacity` without reällocating.
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self {
+			bits: BitVec::repeat(false, round_up::<T>(capacity)),
+		}
+	}
+
+	/// Gets the number of values that the set can hold without growing.
+	///
+	/// All members of the set are less than this number.
+	pub fn capacity(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Counts the number of members in the set.
+	///
+	/// This is not cached, and counts over the entire bit-map.
+	pub fn len(&self) -> usize {
+		self.bits.count_ones()
+	}
+
+	/// Tests if the set has no members.
+	pub fn is_empty(&self) -> bool {
+		self.bits.not_any()
+	}
+
+	/// Tests if a value is a member of the set.
+	pub fn contains(&self, value: usize) -> bool {
+		self.bits.get(value).copied().unwrap_or(false)
+	}
+
+	/// Adds a value to the set, growing the set if it is not large enough to
+	/// hold `value`.
+	///
+	/// # Returns
+	///
+	/// `true` if `value` was not already a member of the set.
+	pub fn insert(&mut self, value: usize) -> bool {
+		if value >= self.capacity() {
+			self.grow(value + 1);
+		}
+		!replace(&mut self.bits, value, true)
+	}
+
+	/// Removes a value from the set.
+	///
+	/// # Returns
+	///
+	/// `true` if `value` was a member of the set.
+	pub fn remove(&mut self, value: usize) -> bool {
+		value < self.capacity() && replace(&mut self.bits, value, false)
+	}
+
+	/// Removes all members from the set, without releasing its memory.
+	pub fn clear(&mut self) {
+		self.bits.set_all(false);
+	}
+
+	/// Iterates over the members of the set, in ascending order.
+	pub fn iter(&self) -> IterOnes<'_, O, T> {
+		self.bits.iter_ones()
+	}
+
+	/// Finds the smallest member of the set.
+	pub fn first(&self) -> Option<usize> {
+		self.bits.first_one()
+	}
+
+	/// Finds the largest member of the set.
+	pub fn last(&self) -> Option<usize> {
+		self.bits.last_one()
+	}
+
+	/// Adds all members of `other` to `self`.
+	pub fn union_with(&mut self, other: &Self) {
+		if other.capacity() > self.capacity() {
+			self.grow(other.capacity());
+		}
+		zip_with(self.bits.as_mut_slice(), other.bits.as_slice(), |a, b| {
+			a | b
+		});
+	}
+
+	/// Removes all members of `self` that are not members of `other`.
+	pub fn intersect_with(&mut self, other: &Self) {
+		let elts = other.bits.as_slice().len();
+		let this = self.bits.as_mut_slice();
+		zip_with(this, other.bits.as_slice(), |a, b| a & b);
+		for elem in this.iter_mut().skip(elts) {
+			elem.store_value(T::Mem::ZERO);
+		}
+	}
+
+	/// Removes all members of `other` from `self`.
+	pub fn difference_with(&mut self, other: &Self) {
+		zip_with(self.bits.as_mut_slice(), other.bits.as_slice(), |a, b| {
+			a & !b
+		});
+	}
+
+	/// Keeps the members of `self` or `other` that are not members of both.
+	pub fn symmetric_difference_with(&mut self, other: &Self) {
+		if other.capacity() > self.capacity() {
+			self.grow(other.capacity());
+		}
+		zip_with(self.bits.as_mut_slice(), other.bits.as_slice(), |a, b| {
+			a ^ b
+		});
+	}
+
+	/// Produces the set of values that are members of `self` or `other`.
+	pub fn union(&self, other: &Self) -> Self {
+		let mut out = self.clone();
+		out.union_with(other);
+		out
+	}
+
+	/// Produces the set of values that are members of both `self` and
+	/// `other`.
+	pub fn intersection(&self, other: &Self) -> Self {
+		let mut out = self.clone();
+		out.intersect_with(other);
+		out
+	}
+
+	/// Produces the set of values that are members of `self` but not of
+	/// `other`.
+	pub fn difference(&self, other: &Self) -> Self {
+		let mut out = self.clone();
+		out.difference_with(other);
+		out
+	}
+
+	/// Produces the set of values that are members of exactly one of `self`
+	/// and `other`.
+	pub fn symmetric_difference(&self, other: &Self) -> Self {
+		let mut out = self.clone();
+		out.symmetric_difference_with(other);
+		out
+	}
+
+	/// Tests if every member of `self` is also a member of `other`.
+	pub fn is_subset(&self, other: &Self) -> bool {
+		is_subset(self.bits.as_slice(), other.bits.as_slice())
+	}
+
+	/// Tests if every member of `other` is also a member of `self`.
+	pub fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
+	}
+
+	/// Tests if `self` and `other` have no members in common.
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		is_disjoint(self.bits.as_slice(), other.bits.as_slice())
+	}
+
+	/// Releases any trailing memory elements that contain no members.
+	pub fn shrink_to_fit(&mut self) {
+		let len = self.last().map(|last| round_up::<T>(last + 1)).unwrap_or(0);
+		self.bits.truncate(len);
+		self.bits.shrink_to_fit();
+	}
+
+	/// Views the member bit-map.
+	pub fn as_bitslice(&self) -> &BitSlice<O, T> {
+		self.bits.as_bitslice()
+	}
+
+	/// Removes the set wrapper, leaving the member bit-map.
+	pub fn into_bitvec(self) -> BitVec<O, T> {
+		self.bits
+	}
+
+	/// Extends the bit-map to hold at least `capacity` bits.
+	fn grow(&mut self, capacity: usize) {
+		self.bits.resize(round_up::<T>(capacity), false);
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> Clone for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn clone(&self) -> Self {
+		Self {
+			bits: self.bits.clone(),
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> Debug for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_set().entries(self.iter()).finish()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> Default for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> Eq for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> PartialEq for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn eq(&self, other: &Self) -> bool {
+		is_subset(self.bits.as_slice(), other.bits.as_slice())
+			&& is_subset(other.bits.as_slice(), self.bits.as_slice())
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> Hash for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		for member in self.iter() {
+			member.hash(hasher);
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> Extend<usize> for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = usize> {
+		for value in iter {
+			self.insert(value);
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, O, T> Extend<&'a usize> for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = &'a usize> {
+		self.extend(iter.into_iter().copied());
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> FromIterator<usize> for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = usize> {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> From<BitVec<O, T>> for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Uses a bit-vector as the member bit-map of a set.
+	///
+	/// The bit-vector is realigned to the front of its buffer, and zero-extended
+	/// to a whole number of elements.
+	fn from(mut bits: BitVec<O, T>) -> Self {
+		bits.force_align();
+		let len = round_up::<T>(bits.len());
+		bits.resize(len, false);
+		Self { bits }
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, O, T> IntoIterator for &'a BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type IntoIter = IterOnes<'a, O, T>;
+	type Item = usize;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/** A fixed-capacity set of small integers.
+
+This stores its members in a [`BitArray`], and so can be held by value without
+an allocator. It can only hold values less than the bit-width of its `V`
+buffer.
+
+# Type Parameters
+
+- `O`: The ordering of bits within the storage elements.
+- `V`: The buffer type, as in [`BitArray`].
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::set::BitArraySet;
+
+let mut flags = BitArraySet::<Lsb0, [u8; 2]>::new();
+assert_eq!(flags.capacity(), 16);
+flags.insert(3);
+flags.insert(12);
+
+let mut other = BitArraySet::<Lsb0, [u8; 2]>::new();
+other.insert(12);
+assert!(other.is_subset(&flags));
+assert_eq!(flags.difference(&other).iter().collect::<Vec<_>>(), [3]);
+```
+
+[`BitArray`]: crate::array::BitArray
+**/
+#[derive(Copy)]
+pub struct BitArraySet<O = Lsb0, V = [usize; 1]>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	/// The member bit-map.
+	bits: BitArray<O, V>,
+}
+
+impl<O, V> BitArraySet<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	/// Constructs a new, empty, set.
+	pub fn new() -> Self {
+		Self {
+			bits: BitArray::zeroed(),
+		}
+	}
+
+	/// Gets the number of values that the set can hold.
+	///
+	/// All members of the set are less than this number.
+	pub fn capacity(&self) -> usize {
+		V::const_bits()
+	}
+
+	/// Counts the number of members in the set.
+	pub fn len(&self) -> usize {
+		self.bits.count_ones()
+	}
+
+	/// Tests if the set has no members.
+	pub fn is_empty(&self) -> bool {
+		self.bits.not_any()
+	}
+
+	/// Tests if a value is a member of the set.
+	pub fn contains(&self, value: usize) -> bool {
+		self.bits.get(value).copied().unwrap_or(false)
+	}
+
+	/// Adds a value to the set.
+	///
+	/// # Returns
+	///
+	/// `true` if `value` was not already a member of the set.
+	///
+	/// # Panics
+	///
+	/// This panics if `value` is not less than the set capacity.
+	pub fn insert(&mut self, value: usize) -> bool {
+		let cap = self.capacity();
+		assert!(
+			value < cap,
+			"Cannot insert {} into a set of capacity {}",
+			value,
+			cap
+		);
+		!replace(&mut self.bits, value, true)
+	}
+
+	/// Removes a value from the set.
+	///
+	/// # Returns
+	///
+	/// `true` if `value` was a member of the set.
+	pub fn remove(&mut self, value: usize) -> bool {
+		value < self.capacity() && replace(&mut self.bits, value, false)
+	}
+
+	/// Removes all members from the set.
+	pub fn clear(&mut self) {
+		self.bits.set_all(false);
+	}
+
+	/// Iterates over the members of the set, in ascending order.
+	pub fn iter(&self) -> IterOnes<'_, O, V::Store> {
+		self.bits.iter_ones()
+	}
+
+	/// Finds the smallest member of the set.
+	pub fn first(&self) -> Option<usize> {
+		self.bits.first_one()
+	}
+
+	/// Finds the largest member of the set.
+	pub fn last(&self) -> Option<usize> {
+		self.bits.last_one()
+	}
+
+	/// Adds all members of `other` to `self`.
+	pub fn union_with(&mut self, other: &Self) {
+		zip_with(self.bits.as_mut_slice(), other.bits.as_slice(), |a, b| {
+			a | b
+		});
+	}
+
+	/// Removes all members of `self` that are not members of `other`.
+	pub fn intersect_with(&mut self, other: &Self) {
+		zip_with(self.bits.as_mut_slice(), other.bits.as_slice(), |a, b| {
+			a & b
+		});
+	}
+
+	/// Removes all members of `other` from `self`.
+	pub fn difference_with(&mut self, other: &Self) {
+		zip_with(self.bits.as_mut_slice(), other.bits.as_slice(), |a, b| {
+			a & !b
+		});
+	}
+
+	/// Keeps the members of `self` or `other` that are not members of both.
+	pub fn symmetric_difference_with(&mut self, other: &Self) {
+		zip_with(self.bits.as_mut_slice(), other.bits.as_slice(), |a, b| {
+			a ^ b
+		});
+	}
+
+	/// Produces the set of values that are members of `self` or `other`.
+	pub fn union(&self, other: &Self) -> Self {
+		let mut out = self.clone();
+		out.union_with(other);
+		out
+	}
+
+	/// Produces the set of values that are members of both `self` and
+	/// `other`.
+	pub fn intersection(&self, other: &Self) -> Self {
+		let mut out = self.clone();
+		out.intersect_with(other);
+		out
+	}
+
+	/// Produces the set of values that are members of `self` but not of
+	/// `other`.
+	pub fn difference(&self, other: &Self) -> Self {
+		let mut out = self.clone();
+		out.difference_with(other);
+		out
+	}
+
+	/// Produces the set of values that are members of exactly one of `self`
+	/// and `other`.
+	pub fn symmetric_difference(&self, other: &Self) -> Self {
+		let mut out = self.clone();
+		out.symmetric_difference_with(other);
+		out
+	}
+
+	/// Tests if every member of `self` is also a member of `other`.
+	pub fn is_subset(&self, other: &Self) -> bool {
+		is_subset(self.bits.as_slice(), other.bits.as_slice())
+	}
+
+	/// Tests if every member of `other` is also a member of `self`.
+	pub fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
+	}
+
+	/// Tests if `self` and `other` have no members in common.
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		is_disjoint(self.bits.as_slice(), other.bits.as_slice())
+	}
+
+	/// Views the member bit-map.
+	pub fn as_bitslice(&self) -> &BitSlice<O, V::Store> {
+		self.bits.as_bitslice()
+	}
+
+	/// Removes the set wrapper, leaving the member bit-map.
+	pub fn into_bitarray(self) -> BitArray<O, V> {
+		self.bits
+	}
+}
+
+impl<O, V> Clone for BitArraySet<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn clone(&self) -> Self {
+		Self {
+			bits: self.bits.clone(),
+		}
+	}
+}
+
+impl<O, V> Debug for BitArraySet<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_set().entries(self.iter()).finish()
+	}
+}
+
+impl<O, V> Default for BitArraySet<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<O, V> Eq for BitArraySet<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+}
+
+impl<O, V> PartialEq for BitArraySet<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn eq(&self, other: &Self) -> bool {
+		self.bits == other.bits
+	}
+}
+
+impl<O, V> Hash for BitArraySet<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		for member in self.iter() {
+			member.hash(hasher);
+		}
+	}
+}
+
+impl<O, V> Extend<usize> for BitArraySet<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	/// Inserts each value of an iterator into the set.
+	///
+	/// # Panics
+	///
+	/// This panics if any value is not less than the set capacity.
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = usize> {
+		for value in iter {
+			self.insert(value);
+		}
+	}
+}
+
+impl<'a, O, V> Extend<&'a usize> for BitArraySet<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = &'a usize> {
+		self.extend(iter.into_iter().copied());
+	}
+}
+
+impl<O, V> FromIterator<usize> for BitArraySet<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	/// Collects an iterator of values into a set.
+	///
+	/// # Panics
+	///
+	/// This panics if any value is not less than the set capacity.
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = usize> {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+impl<O, V> From<BitArray<O, V>> for BitArraySet<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn from(bits: BitArray<O, V>) -> Self {
+		Self { bits }
+	}
+}
+
+impl<'a, O, V> IntoIterator for &'a BitArraySet<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	type IntoIter = IterOnes<'a, O, V::Store>;
+	type Item = usize;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// Rounds a bit count up to a whole number of `T` elements.
+#[cfg(feature = "alloc")]
+fn round_up<T>(bits: usize) -> usize
+where T: BitStore {
+	crate::mem::elts::<T>(bits) * <T::Mem as BitMemory>::BITS as usize
+}
+
+/// Writes a bit, returning its previous value.
+fn replace<O, T>(bits: &mut BitSlice<O, T>, index: usize, value: bool) -> bool
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let old = bits[index];
+	bits.set(index, value);
+	old
+}
+
+/// Combines each element of `dst` with its counterpart in `src`.
+///
+/// Elements of `dst` with no counterpart in `src` are not modified.
+fn zip_with<T, F>(dst: &mut [T], src: &[T], func: F)
+where
+	T: BitStore,
+	F: Fn(T::Mem, T::Mem) -> T::Mem,
+{
+	for (a, b) in dst.iter_mut().zip(src) {
+		a.store_value(func(a.load_value(), b.load_value()));
+	}
+}
+
+/// Tests if every bit set in `lhs` is also set in `rhs`.
+///
+/// Elements missing from the shorter slice are treated as zero.
+fn is_subset<T>(lhs: &[T], rhs: &[T]) -> bool
+where T: BitStore {
+	let common = cmp::min(lhs.len(), rhs.len());
+	lhs.iter()
+		.zip(rhs)
+		.all(|(a, b)| a.load_value() & !b.load_value() == T::Mem::ZERO)
+		&& lhs[common ..]
+			.iter()
+			.all(|a| a.load_value() == T::Mem::ZERO)
+}
+
+/// Tests if no bit is set in both `lhs` and `rhs`.
+fn is_disjoint<T>(lhs: &[T], rhs: &[T]) -> bool
+where T: BitStore {
+	lhs.iter()
+		.zip(rhs)
+		.all(|(a, b)| a.load_value() & b.load_value() == T::Mem::ZERO)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	use alloc::vec::Vec;
+
+	#[test]
+	fn bit_set() {
+		let mut a = BitSet::<Msb0, u8>::new();
+		assert!(a.is_empty());
+		assert!(a.insert(20));
+		assert!(!a.insert(20));
+		assert_eq!(a.capacity(), 24);
+		a.extend(&[1, 5, 9]);
+		assert_eq!(a.len(), 4);
+		assert_eq!(a.first(), Some(1));
+		assert_eq!(a.last(), Some(20));
+		assert!(a.remove(5));
+		assert!(!a.remove(5));
+		assert!(!a.remove(1000));
+		assert!(!a.contains(1000));
+
+		let b: BitSet<Msb0, u8> = (0 .. 40).filter(|n| n % 4 == 1).collect();
+		assert_eq!(a.union(&b).len(), 11);
+		assert_eq!(b.union(&a), a.union(&b));
+		assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), [1, 9]);
+		assert_eq!(b.intersection(&a), a.intersection(&b));
+		assert_eq!(a.difference(&b).iter().collect::<Vec<_>>(), [20]);
+		assert_eq!(b.difference(&a).len(), 8);
+		assert_eq!(
+			a.symmetric_difference(&b),
+			a.union(&b).difference(&a.intersection(&b)),
+		);
+
+		assert!(a.intersection(&b).is_subset(&a));
+		assert!(b.is_superset(&a.intersection(&b)));
+		assert!(!a.is_subset(&b));
+		assert!(a.difference(&b).is_disjoint(&b));
+		assert!(!a.is_disjoint(&b));
+
+		let mut c = a.union(&b);
+		c.intersect_with(&BitSet::new());
+		assert!(c.is_empty());
+		assert_eq!(c, BitSet::new());
+		c.shrink_to_fit();
+		assert_eq!(c.capacity(), 0);
+
+		let d = BitSet::from(bitvec![Msb0, u8; 0, 1, 1]);
+		assert_eq!(d.capacity(), 8);
+		assert_eq!(d.iter().collect::<Vec<_>>(), [1, 2]);
+	}
+
+	#[test]
+	fn bit_array_set() {
+		let mut a = BitArraySet::<Lsb0, [u16; 2]>::new();
+		assert_eq!(a.capacity(), 32);
+		a.extend(&[0, 15, 16, 31]);
+		let b: BitArraySet<Lsb0, [u16; 2]> = (0 .. 32).step_by(3).collect();
+
+		assert_eq!(a.union(&b).len(), 13);
+		assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), [0, 15]);
+		assert_eq!(a.difference(&b).iter().collect::<Vec<_>>(), [16, 31]);
+		assert_eq!(a.symmetric_difference(&b).len(), 11);
+		assert!(a.intersection(&b).is_subset(&b));
+		assert!(a.difference(&b).is_disjoint(&b));
+		assert!(!a.contains(32));
+		assert!(!a.remove(32));
+	}
+
+	#[test]
+	#[should_panic]
+	fn bit_array_set_overflow() {
+		BitArraySet::<Lsb0, [u8; 1]>::new().insert(8);
+	}
+}
@@ -43,6 +43,7 @@ use crate::{
 		BitAccess,
 		BitSafe,
 	},
+	combine::BitExpr,
 	devel as dvl,
 	domain::{
 		BitDomain,
@@ -69,10 +70,12 @@ use crate::{
 
 use core::{
 	any::TypeId,
+	cmp,
 	marker::PhantomData,
 	ops::RangeBounds,
 	ptr,
 	slice,
+	sync::atomic::Ordering,
 };
 
 use funty::IsInteger;
@@ -82,6 +85,14 @@ use tap::pipe::Pipe;
 #[cfg(feature = "alloc")]
 use crate::vec::BitVec;
 
+/// A masked read-modify-write operation on a memory element, returning its
+/// previous value.
+type FetchOp<T> = fn(
+	&<T as BitStore>::Access,
+	BitMask<<T as BitStore>::Mem>,
+	Ordering,
+) -> <T as BitStore>::Mem;
+
 /** A slice of individual bits, anywhere in memory.
 
 `BitSlice<O, T>` is an unsized region type; you interact with it through
@@ -803,6 +814,189 @@ where
 		}
 	}
 
+	/// Sets a bit to `1` through a shared reference, and returns its previous
+	/// value.
+	///
+	/// This has the same requirements as [`.set_aliased()`], and performs a
+	/// single read-modify-write instruction on the element containing the bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit to set.
+	/// - `order`: The memory ordering of the read-modify-write operation.
+	///
+	/// # Returns
+	///
+	/// The value of the bit before it was set.
+	///
+	/// # Panics
+	///
+	/// This method panics if `index` is not less than [`self.len()`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::{AtomicU64, Ordering};
+	///
+	/// let data = [AtomicU64::new(0), AtomicU64::new(0)];
+	/// let bits = BitSlice::<Lsb0, _>::from_slice(&data).unwrap();
+	///
+	/// assert!(!bits.fetch_set(70, Ordering::AcqRel));
+	/// assert!(bits.fetch_set(70, Ordering::AcqRel));
+	/// assert_eq!(data[1].load(Ordering::Relaxed), 1 << 6);
+	/// ```
+	///
+	/// [`.set_aliased()`]: Self::set_aliased
+	/// [`self.len()`]: Self::len
+	pub fn fetch_set(&self, index: usize, order: Ordering) -> bool
+	where T: radium::Radium {
+		self.fetch_bit(index, order, T::Access::fetch_set_bits)
+	}
+
+	/// Clears a bit to `0` through a shared reference, and returns its
+	/// previous value.
+	///
+	/// See [`.fetch_set()`] for details.
+	///
+	/// [`.fetch_set()`]: Self::fetch_set
+	pub fn fetch_clear(&self, index: usize, order: Ordering) -> bool
+	where T: radium::Radium {
+		self.fetch_bit(index, order, T::Access::fetch_clear_bits)
+	}
+
+	/// Inverts a bit through a shared reference, and returns its previous
+	/// value.
+	///
+	/// See [`.fetch_set()`] for details.
+	///
+	/// [`.fetch_set()`]: Self::fetch_set
+	pub fn fetch_toggle(&self, index: usize, order: Ordering) -> bool
+	where T: radium::Radium {
+		self.fetch_bit(index, order, T::Access::fetch_invert_bits)
+	}
+
+	/// Writes `new` into a bit through a shared reference, if the bit is
+	/// currently `current`.
+	///
+	/// Concurrent modifications to other bits in the same memory element do
+	/// not cause the exchange to fail.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit to exchange.
+	/// - `current`: The value the bit must have for the exchange to occur.
+	/// - `new`: The value to write into the bit.
+	/// - `success`: The memory ordering of the read-modify-write operation if
+	///   the exchange occurs.
+	/// - `failure`: The memory ordering of the load if it does not. This has
+	///   the same restrictions as in [`AtomicU8::compare_exchange`].
+	///
+	/// # Returns
+	///
+	/// `Ok(current)` if the bit was replaced, or `Err` of the observed bit,
+	/// which is `!current`, if it was not.
+	///
+	/// # Panics
+	///
+	/// This method panics if `index` is not less than [`self.len()`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::{AtomicU8, Ordering::*};
+	///
+	/// let byte = AtomicU8::new(0);
+	/// let bits = byte.view_bits::<Msb0>();
+	/// let claim = || bits.compare_exchange_bit(1, false, true, AcqRel, Acquire);
+	/// assert_eq!(claim(), Ok(false));
+	/// assert_eq!(claim(), Err(true));
+	/// assert_eq!(byte.load(Relaxed), 0x40);
+	/// ```
+	///
+	/// [`AtomicU8::compare_exchange`]: core::sync::atomic::AtomicU8::compare_exchange
+	/// [`self.len()`]: Self::len
+	pub fn compare_exchange_bit(
+		&self,
+		index: usize,
+		current: bool,
+		new: bool,
+		success: Ordering,
+		failure: Ordering,
+	) -> Result<bool, bool>
+	where
+		T: radium::Radium,
+	{
+		self.assert_in_bounds(index);
+		let (elem, bit) = unsafe { self.access_bit(index) };
+		elem.compare_exchange_bit::<O>(bit, current, new, success, failure)
+	}
+
+	/// Sets every bit in a range to `1` through a shared reference.
+	///
+	/// Each memory element touched by the range is modified with a single
+	/// read-modify-write instruction. The range as a whole is **not** modified
+	/// atomically: other threads may observe some elements written before
+	/// others.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `range`: The range of bits to set.
+	/// - `order`: The memory ordering of each read-modify-write operation.
+	///
+	/// # Returns
+	///
+	/// The number of bits in the range that were already `1` when their
+	/// element was modified. A return value of `0` means that every bit in the
+	/// range was set by this call.
+	///
+	/// # Panics
+	///
+	/// This method panics if `range` extends beyond [`self.len()`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::{AtomicU16, Ordering};
+	///
+	/// let data = [AtomicU16::new(0), AtomicU16::new(0)];
+	/// let bits = BitSlice::<Msb0, _>::from_slice(&data).unwrap();
+	///
+	/// assert_eq!(bits.fetch_or_range(4 .. 20, Ordering::AcqRel), 0);
+	/// assert_eq!(bits.fetch_or_range(18 .. 22, Ordering::AcqRel), 2);
+	/// assert_eq!(bits.fetch_and_range(.. 8, Ordering::AcqRel), 4);
+	/// assert_eq!(data[0].load(Ordering::Relaxed), 0x00FF);
+	/// assert_eq!(data[1].load(Ordering::Relaxed), 0xFC00);
+	/// ```
+	///
+	/// [`self.len()`]: Self::len
+	pub fn fetch_or_range<R>(&self, range: R, order: Ordering) -> usize
+	where
+		R: RangeBounds<usize>,
+		T: radium::Radium,
+	{
+		self.fetch_range(range, order, T::Access::fetch_set_bits)
+	}
+
+	/// Clears every bit in a range to `0` through a shared reference.
+	///
+	/// See [`.fetch_or_range()`] for details. The return value is again the
+	/// number of bits in the range that were `1` before they were cleared.
+	///
+	/// [`.fetch_or_range()`]: Self::fetch_or_range
+	pub fn fetch_and_range<R>(&self, range: R, order: Ordering) -> usize
+	where
+		R: RangeBounds<usize>,
+		T: radium::Radium,
+	{
+		self.fetch_range(range, order, T::Access::fetch_clear_bits)
+	}
+
 	/// Tests if *any* bit in the slice is set (logical `∨`).
 	///
 	/// # Truth Table
@@ -1106,6 +1300,27 @@ where
 		}
 	}
 
+	/// Computes the GF(2) dot product of `self` and another expression.
+	///
+	/// This is the parity of the bit-wise AND of the two sequences, as produced
+	/// by [`.zip_and()`]. Only the overlapping prefix of the sequences is used.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![1, 1, 0, 1];
+	/// assert!(!a.dot(bits![1, 0, 1, 1]));
+	/// assert!(a.dot(bits![Msb0, u8; 0, 1, 1, 0]));
+	/// ```
+	///
+	/// [`.zip_and()`]: Self::zip_and
+	pub fn dot<R>(&self, rhs: R) -> bool
+	where R: BitExpr {
+		self.zip_and(rhs).count_ones() & 1 == 1
+	}
+
 	/// Enumerates all bits in a `BitSlice` that are set to `1`.
 	///
 	/// # Examples
@@ -1156,6 +1371,444 @@ where
 		IterZeros::new(self)
 	}
 
+	/// Enumerates the maximal runs of identical bits in a `BitSlice`.
+	///
+	/// Each run boundary is found with [`.first_one()`] or [`.first_zero()`],
+	/// which scan a memory element at a time.
+	///
+	/// # Returns
+	///
+	/// An iterator of `(bit, range)` pairs, where every bit in `range` is
+	/// `bit`, and adjacent ranges hold different bits. The ranges cover the
+	/// whole slice in order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 1, 1, 0, 1];
+	/// let mut runs = bits.iter_runs();
+	/// assert_eq!(runs.next(), Some((false, 0 .. 2)));
+	/// assert_eq!(runs.next_back(), Some((true, 6 .. 7)));
+	/// assert_eq!(runs.next(), Some((true, 2 .. 5)));
+	/// assert_eq!(runs.next(), Some((false, 5 .. 6)));
+	/// assert!(runs.next().is_none());
+	/// ```
+	///
+	/// [`.first_one()`]: Self::first_one
+	/// [`.first_zero()`]: Self::first_zero
+	pub fn iter_runs(&self) -> IterRuns<'_, O, T> {
+		IterRuns::new(self)
+	}
+
+	/// Enumerates the maximal runs of bits in a `BitSlice` that are set to
+	/// `1`.
+	///
+	/// This is equivalent to filtering [`.iter_runs()`] for runs of `1` bits,
+	/// but skips each run of `0` bits with a single scan.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 0, 1, 0, 1];
+	/// let ranges = bits.iter_one_ranges().collect::<Vec<_>>();
+	/// assert_eq!(ranges, [1 .. 3, 5 .. 6, 7 .. 8]);
+	/// ```
+	///
+	/// [`.iter_runs()`]: Self::iter_runs
+	pub fn iter_one_ranges(&self) -> IterOneRanges<'_, O, T> {
+		IterOneRanges::new(self)
+	}
+
+	/// Finds the index of the first bit in the slice that is set to `1`.
+	///
+	/// This searches a memory element at a time, rather than bit-by-bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The index of the first `1` bit in the slice, if any.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![0, 0, 1, 0, 1].first_one(), Some(2));
+	/// assert!(bits![0; 20].first_one().is_none());
+	/// ```
+	pub fn first_one(&self) -> Option<usize> {
+		self.find_first(true)
+	}
+
+	/// Finds the index of the first bit in the slice that is cleared to `0`.
+	///
+	/// This searches a memory element at a time, rather than bit-by-bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The index of the first `0` bit in the slice, if any.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![1, 1, 0, 1, 0].first_zero(), Some(2));
+	/// assert!(bits![1; 20].first_zero().is_none());
+	/// ```
+	pub fn first_zero(&self) -> Option<usize> {
+		self.find_first(false)
+	}
+
+	/// Finds the index of the last bit in the slice that is set to `1`.
+	///
+	/// This searches a memory element at a time, rather than bit-by-bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The index of the last `1` bit in the slice, if any.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![1, 0, 1, 0, 0].last_one(), Some(2));
+	/// assert!(bits![0; 20].last_one().is_none());
+	/// ```
+	pub fn last_one(&self) -> Option<usize> {
+		self.find_last(true)
+	}
+
+	/// Finds the index of the last bit in the slice that is cleared to `0`.
+	///
+	/// This searches a memory element at a time, rather than bit-by-bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The index of the last `0` bit in the slice, if any.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![0, 1, 0, 1, 1].last_zero(), Some(2));
+	/// assert!(bits![1; 20].last_zero().is_none());
+	/// ```
+	pub fn last_zero(&self) -> Option<usize> {
+		self.find_last(false)
+	}
+
+	/// Counts the number of bits from the start of the slice to the first bit
+	/// set to `1`.
+	///
+	/// Note that “leading” refers to the front of the slice (index `0`), not to
+	/// the most significant bit of any memory element.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The length of the run of `0` bits at the front of the slice. This is the
+	/// slice length if the slice has no `1` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![0, 0, 1, 0].leading_zeros(), 2);
+	/// assert_eq!(bits![0; 5].leading_zeros(), 5);
+	/// ```
+	pub fn leading_zeros(&self) -> usize {
+		self.first_one().unwrap_or_else(|| self.len())
+	}
+
+	/// Counts the number of bits from the start of the slice to the first bit
+	/// cleared to `0`.
+	///
+	/// Note that “leading” refers to the front of the slice (index `0`), not to
+	/// the most significant bit of any memory element.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The length of the run of `1` bits at the front of the slice. This is the
+	/// slice length if the slice has no `0` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![1, 1, 0, 1].leading_ones(), 2);
+	/// assert_eq!(bits![1; 5].leading_ones(), 5);
+	/// ```
+	pub fn leading_ones(&self) -> usize {
+		self.first_zero().unwrap_or_else(|| self.len())
+	}
+
+	/// Counts the number of bits from the last bit set to `1` to the end of
+	/// the slice.
+	///
+	/// Note that “trailing” refers to the back of the slice (index
+	/// `len - 1`), not to the least significant bit of any memory element.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The length of the run of `0` bits at the back of the slice. This is the
+	/// slice length if the slice has no `1` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![0, 1, 0, 0].trailing_zeros(), 2);
+	/// assert_eq!(bits![0; 5].trailing_zeros(), 5);
+	/// ```
+	pub fn trailing_zeros(&self) -> usize {
+		let len = self.len();
+		self.last_one().map_or(len, |idx| len - 1 - idx)
+	}
+
+	/// Counts the number of bits from the last bit cleared to `0` to the end
+	/// of the slice.
+	///
+	/// Note that “trailing” refers to the back of the slice (index
+	/// `len - 1`), not to the least significant bit of any memory element.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The length of the run of `1` bits at the back of the slice. This is the
+	/// slice length if the slice has no `0` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![1, 0, 1, 1].trailing_ones(), 2);
+	/// assert_eq!(bits![1; 5].trailing_ones(), 5);
+	/// ```
+	pub fn trailing_ones(&self) -> usize {
+		let len = self.len();
+		self.last_zero().map_or(len, |idx| len - 1 - idx)
+	}
+
+	/// Finds the first occurrence of a bit-pattern in the slice.
+	///
+	/// The search loads the slice a processor word at a time, and tests every
+	/// candidate position within that word against the front of the pattern
+	/// before falling back to a full comparison.
+	///
+	/// # Type Parameters
+	///
+	/// - `O2`: The ordering of the pattern. This does not need to match `O`.
+	/// - `T2`: The storage type of the pattern. This does not need to match
+	///   `T`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The bit-pattern to search for.
+	///
+	/// # Returns
+	///
+	/// The index in `self` at which the first occurrence of `needle` begins, if
+	/// any. An empty `needle` is found at index `0`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 1, 0, 1, 1, 0];
+	/// assert_eq!(bits.find(bits![1, 1, 0]), Some(2));
+	/// assert_eq!(bits.find(bits![Msb0, u16; 0, 1]), Some(1));
+	/// assert!(bits.find(bits![1, 1, 1]).is_none());
+	/// ```
+	pub fn find<O2, T2>(&self, needle: &BitSlice<O2, T2>) -> Option<usize>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let (len, width) = (self.len(), needle.len());
+		if width > len {
+			return None;
+		}
+		if width == 0 {
+			return Some(0);
+		}
+
+		let word = <usize as BitMemory>::BITS as usize;
+		let (front, rest) = needle.split_at(cmp::min(width, word));
+		let (pattern, mask) = (front.load_ordered(), !0 >> (word - front.len()));
+		//  The last index at which `needle` can begin.
+		let last = len - width;
+
+		let mut start = 0;
+		while start <= last {
+			let span = cmp::min(word, len - start);
+			let window = self[start .. start + span].load_ordered();
+			//  Every position in the window at which the front of `needle` fits
+			//  is tested against the loaded word.
+			let tests = cmp::min(span - front.len(), last - start) + 1;
+			for shamt in 0 .. tests {
+				let pos = start + shamt;
+				if (window >> shamt) & mask == pattern
+					&& self[pos + front.len() .. pos + width].ordered_eq(rest)
+				{
+					return Some(pos);
+				}
+			}
+			start += tests;
+		}
+		None
+	}
+
+	/// Finds the last occurrence of a bit-pattern in the slice.
+	///
+	/// This is the reverse of [`.find()`], and uses the same word-at-a-time
+	/// search strategy.
+	///
+	/// # Type Parameters
+	///
+	/// - `O2`: The ordering of the pattern. This does not need to match `O`.
+	/// - `T2`: The storage type of the pattern. This does not need to match
+	///   `T`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The bit-pattern to search for.
+	///
+	/// # Returns
+	///
+	/// The index in `self` at which the last occurrence of `needle` begins, if
+	/// any. An empty `needle` is found at index `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 1, 1, 0, 0];
+	/// assert_eq!(bits.rfind(bits![1, 1, 0]), Some(4));
+	/// assert_eq!(bits.rfind(bits![]), Some(8));
+	/// ```
+	///
+	/// [`.find()`]: Self::find
+	pub fn rfind<O2, T2>(&self, needle: &BitSlice<O2, T2>) -> Option<usize>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let (len, width) = (self.len(), needle.len());
+		if width > len {
+			return None;
+		}
+		if width == 0 {
+			return Some(len);
+		}
+
+		let word = <usize as BitMemory>::BITS as usize;
+		let (front, rest) = needle.split_at(cmp::min(width, word));
+		let (pattern, mask) = (front.load_ordered(), !0 >> (word - front.len()));
+
+		//  The highest position not yet tested.
+		let mut end = len - width;
+		loop {
+			let start = end.saturating_sub(word - front.len());
+			let window = self[start .. end + front.len()].load_ordered();
+			for pos in (start ..= end).rev() {
+				if (window >> (pos - start)) & mask == pattern
+					&& self[pos + front.len() .. pos + width].ordered_eq(rest)
+				{
+					return Some(pos);
+				}
+			}
+			if start == 0 {
+				return None;
+			}
+			end = start - 1;
+		}
+	}
+
+	/// Enumerates the non-overlapping occurrences of a bit-pattern in the
+	/// slice.
+	///
+	/// # Type Parameters
+	///
+	/// - `O2`: The ordering of the pattern. This does not need to match `O`.
+	/// - `T2`: The storage type of the pattern. This does not need to match
+	///   `T`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The bit-pattern to search for.
+	///
+	/// # Returns
+	///
+	/// An iterator over the starting index of each occurrence of `needle`,
+	/// from front to back. Once an occurrence is found, the search resumes
+	/// after its end, so occurrences that overlap an earlier match are not
+	/// produced.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 1, 0, 1, 1, 0, 1, 1, 1];
+	/// let found = bits.match_indices(bits![1, 1]).collect::<Vec<_>>();
+	/// assert_eq!(found, [0, 4, 7]);
+	/// ```
+	pub fn match_indices<'a, O2, T2>(
+		&'a self,
+		needle: &'a BitSlice<O2, T2>,
+	) -> MatchIndices<'a, O, T, O2, T2>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		MatchIndices::new(self, needle)
+	}
+
 	/// Copies the bits from `src` into `self`.
 	///
 	/// The length of `src` must be the same as `self.
@@ -2094,6 +2747,69 @@ where
 		assert!(index < len, "Index out of range: {} >= {}", index, len);
 	}
 
+	/// Finds the access reference and bit index of a bit, without bounds
+	/// checking.
+	///
+	/// # Safety
+	///
+	/// `index` must be less than `self.len()`.
+	unsafe fn access_bit(&self, index: usize) -> (&T::Access, BitIdx<T::Mem>) {
+		let (elt, bit) = self.bitptr().head().offset(index as isize);
+		(&*self.bitptr().pointer().to_access().offset(elt), bit)
+	}
+
+	/// Applies a masked read-modify-write operation to one bit.
+	fn fetch_bit(
+		&self,
+		index: usize,
+		order: Ordering,
+		op: FetchOp<T>,
+	) -> bool
+	{
+		self.assert_in_bounds(index);
+		let (elem, bit) = unsafe { self.access_bit(index) };
+		let sel = bit.select::<O>();
+		op(elem, sel.mask(), order) & sel.value() != T::Mem::ZERO
+	}
+
+	/// Applies a masked read-modify-write operation to every element touched
+	/// by a range, and counts the bits in the range that were set beforehand.
+	fn fetch_range<R>(
+		&self,
+		range: R,
+		order: Ordering,
+		op: FetchOp<T>,
+	) -> usize
+	where
+		R: RangeBounds<usize>,
+	{
+		let range = dvl::normalize_range(range, self.len());
+		dvl::assert_range(range.clone(), self.len());
+		let apply = |elem: &T, mask: BitMask<T::Mem>| {
+			let elem = unsafe { &*(elem as *const T as *const T::Access) };
+			(op(elem, mask, order) & mask.value()).count_ones() as usize
+		};
+		match unsafe { self.get_unchecked(range) }.domain() {
+			Domain::Enclave { head, elem, tail } => {
+				apply(elem, O::mask(head, tail))
+			},
+			Domain::Region { head, body, tail } => {
+				let mut count = 0;
+				if let Some((head, elem)) = head {
+					count += apply(elem, O::mask(head, None));
+				}
+				for elem in body {
+					let elem = unsafe { &*(elem as *const _ as *const T) };
+					count += apply(elem, BitMask::ALL);
+				}
+				if let Some((elem, tail)) = tail {
+					count += apply(elem, O::mask(None, tail));
+				}
+				count
+			},
+		}
+	}
+
 	/// Marks an immutable slice as referring to aliased memory region.
 	pub(crate) fn alias(&self) -> &BitSlice<O, T::Alias> {
 		unsafe { &*(self.as_bitptr() as *const BitSlice<O, T::Alias>) }
@@ -2142,6 +2858,235 @@ where
 		//  Remove the new alias layer.
 		(Self::unalias_mut(head), Self::unalias_mut(tail))
 	}
+
+	/// Finds the first bit in the slice equal to `value`, searching a memory
+	/// element at a time.
+	pub(crate) fn find_first(&self, value: bool) -> Option<usize> {
+		let load = |elem: T::Mem| if value { elem } else { !elem };
+		let width = T::Mem::BITS as usize;
+		match self.domain() {
+			Domain::Enclave { head, elem, tail } => {
+				Self::elem_first(load(elem.load_value()), O::mask(head, tail))
+					.map(|idx| (idx - head.value()) as usize)
+			},
+			Domain::Region { head, body, tail } => {
+				let mut offset = 0;
+				if let Some((head, elem)) = head {
+					let mask = O::mask(head, None);
+					if let Some(idx) = Self::elem_first(load(elem.load_value()), mask)
+					{
+						return Some((idx - head.value()) as usize);
+					}
+					offset = width - head.value() as usize;
+				}
+				for elem in body {
+					let val = load(elem.load_value());
+					if let Some(idx) = Self::elem_first(val, BitMask::ALL) {
+						return Some(offset + idx as usize);
+					}
+					offset += width;
+				}
+				tail.and_then(|(elem, tail)| {
+					Self::elem_first(load(elem.load_value()), O::mask(None, tail))
+				})
+				.map(|idx| offset + idx as usize)
+			},
+		}
+	}
+
+	/// Finds the last bit in the slice equal to `value`, searching a memory
+	/// element at a time.
+	pub(crate) fn find_last(&self, value: bool) -> Option<usize> {
+		let load = |elem: T::Mem| if value { elem } else { !elem };
+		let width = T::Mem::BITS as usize;
+		match self.domain() {
+			Domain::Enclave { head, elem, tail } => {
+				Self::elem_last(load(elem.load_value()), O::mask(head, tail))
+					.map(|idx| (idx - head.value()) as usize)
+			},
+			Domain::Region { head, body, tail } => {
+				//  The offset of the first bit after the element being searched.
+				let mut end = self.len();
+				if let Some((elem, tail)) = tail {
+					let mask = O::mask(None, tail);
+					end -= tail.value() as usize;
+					if let Some(idx) = Self::elem_last(load(elem.load_value()), mask)
+					{
+						return Some(end + idx as usize);
+					}
+				}
+				for elem in body.iter().rev() {
+					end -= width;
+					let val = load(elem.load_value());
+					if let Some(idx) = Self::elem_last(val, BitMask::ALL) {
+						return Some(end + idx as usize);
+					}
+				}
+				head.and_then(|(head, elem)| {
+					Self::elem_last(load(elem.load_value()), O::mask(head, None))
+						.map(|idx| (idx - head.value()) as usize)
+				})
+			},
+		}
+	}
+
+	/// Finds the lowest index in a memory element that is both set in the
+	/// element and selected by the mask.
+	///
+	/// The `Lsb0` and `Msb0` orderings use the processor’s bit-scan
+	/// instructions; all other orderings test each index in turn.
+	fn elem_first(elem: T::Mem, mask: BitMask<T::Mem>) -> Option<u8> {
+		let val = (mask & elem).value();
+		if val == T::Mem::ZERO {
+			return None;
+		}
+		if TypeId::of::<O>() == TypeId::of::<Lsb0>() {
+			Some(val.trailing_zeros() as u8)
+		}
+		else if TypeId::of::<O>() == TypeId::of::<Msb0>() {
+			Some(val.leading_zeros() as u8)
+		}
+		else {
+			let mask = BitMask::new(val);
+			BitIdx::<T::Mem>::range_all()
+				.find(|idx| mask.test(idx.select::<O>()))
+				.map(BitIdx::value)
+		}
+	}
+
+	/// Finds the highest index in a memory element that is both set in the
+	/// element and selected by the mask.
+	///
+	/// The `Lsb0` and `Msb0` orderings use the processor’s bit-scan
+	/// instructions; all other orderings test each index in turn.
+	fn elem_last(elem: T::Mem, mask: BitMask<T::Mem>) -> Option<u8> {
+		let val = (mask & elem).value();
+		if val == T::Mem::ZERO {
+			return None;
+		}
+		if TypeId::of::<O>() == TypeId::of::<Lsb0>() {
+			Some(T::Mem::MASK - val.leading_zeros() as u8)
+		}
+		else if TypeId::of::<O>() == TypeId::of::<Msb0>() {
+			Some(T::Mem::MASK - val.trailing_zeros() as u8)
+		}
+		else {
+			let mask = BitMask::new(val);
+			BitIdx::<T::Mem>::range_all()
+				.rev()
+				.find(|idx| mask.test(idx.select::<O>()))
+				.map(BitIdx::value)
+		}
+	}
+
+	/// Loads up to a processor word of bits into an integer, with the bit at
+	/// index `0` in the least significant position.
+	///
+	/// Unlike [`BitField`], this is available for all orderings, and produces
+	/// the same value for equal bit-sequences regardless of their type
+	/// parameters. It is used to compare slices a word at a time.
+	///
+	/// # Panics
+	///
+	/// This panics in debug builds if `self` is wider than `usize`.
+	///
+	/// [`BitField`]: crate::field::BitField
+	pub(crate) fn load_ordered(&self) -> usize {
+		debug_assert!(
+			self.len() <= <usize as BitMemory>::BITS as usize,
+			"Cannot load {} bits into a usize",
+			self.len()
+		);
+		if TypeId::of::<O>() == TypeId::of::<Lsb0>() {
+			let this: &BitSlice<Lsb0, T> =
+				unsafe { &*(self as *const _ as *const _) };
+			this.sp_load_ordered()
+		}
+		else if TypeId::of::<O>() == TypeId::of::<Msb0>() {
+			let this: &BitSlice<Msb0, T> =
+				unsafe { &*(self as *const _ as *const _) };
+			this.sp_load_ordered()
+		}
+		else {
+			self.iter()
+				.rev()
+				.fold(0, |accum, bit| (accum << 1) | *bit as usize)
+		}
+	}
+
+	/// Stores up to a processor word of bits from an integer, taking the bit
+	/// at index `0` from the least significant position.
+	///
+	/// This is the inverse of [`.load_ordered()`].
+	///
+	/// # Panics
+	///
+	/// This panics in debug builds if `self` is wider than `usize`.
+	///
+	/// [`.load_ordered()`]: Self::load_ordered
+	pub(crate) fn store_ordered(&mut self, value: usize) {
+		debug_assert!(
+			self.len() <= <usize as BitMemory>::BITS as usize,
+			"Cannot store {} bits from a usize",
+			self.len()
+		);
+		if TypeId::of::<O>() == TypeId::of::<Lsb0>() {
+			let this: &mut BitSlice<Lsb0, T> =
+				unsafe { &mut *(self as *mut _ as *mut _) };
+			this.sp_store_ordered(value)
+		}
+		else if TypeId::of::<O>() == TypeId::of::<Msb0>() {
+			let this: &mut BitSlice<Msb0, T> =
+				unsafe { &mut *(self as *mut _ as *mut _) };
+			this.sp_store_ordered(value)
+		}
+		else {
+			for (idx, mut bit) in self.iter_mut().enumerate() {
+				*bit = (value >> idx) & 1 == 1;
+			}
+		}
+	}
+
+	/// Tests if two equal-length slices have the same contents, comparing a
+	/// word at a time.
+	pub(crate) fn ordered_eq<O2, T2>(&self, other: &BitSlice<O2, T2>) -> bool
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let chunk_size = <usize as BitMemory>::BITS as usize;
+		self.len() == other.len()
+			&& self
+				.chunks(chunk_size)
+				.zip(other.chunks(chunk_size))
+				.all(|(a, b)| a.load_ordered() == b.load_ordered())
+	}
+
+	/// Exclusive-ors an equal-length slice into `self`, a word at a time.
+	///
+	/// # Panics
+	///
+	/// This panics if the slices have different lengths.
+	#[cfg(feature = "alloc")]
+	pub(crate) fn xor_ordered<O2, T2>(&mut self, other: &BitSlice<O2, T2>)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		assert_eq!(
+			self.len(),
+			other.len(),
+			"Cannot XOR a slice of {} bits into a slice of {} bits",
+			other.len(),
+			self.len(),
+		);
+		let chunk_size = <usize as BitMemory>::BITS as usize;
+		for (dst, src) in self.chunks_mut(chunk_size).zip(other.chunks(chunk_size))
+		{
+			let value = dst.load_ordered() ^ src.load_ordered();
+			dst.store_ordered(value);
+		}
+	}
 }
 
 /// Methods available only when `T` allows shared mutability.
@@ -2374,6 +3319,7 @@ where
 mod api;
 mod iter;
 mod ops;
+mod par;
 mod proxy;
 mod specialization;
 mod traits;
@@ -2395,8 +3341,11 @@ pub use self::{
 		ChunksMut,
 		Iter,
 		IterMut,
+		IterOneRanges,
 		IterOnes,
+		IterRuns,
 		IterZeros,
+		MatchIndices,
 		RChunks,
 		RChunksExact,
 		RChunksExactMut,
@@ -2411,6 +3360,10 @@ pub use self::{
 		SplitNMut,
 		Windows,
 	},
+	par::{
+		ChunksExactAlignedMut,
+		ParChunksMut,
+	},
 	proxy::BitMut,
 };
 
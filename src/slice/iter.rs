@@ -23,6 +23,7 @@ use core::{
 	iter::FusedIterator,
 	marker::PhantomData,
 	mem,
+	ops::Range,
 	ptr::NonNull,
 };
 
@@ -2373,7 +2374,7 @@ where
 	type Item = usize;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		match self.inner.iter().copied().position(|b| b) {
+		match self.inner.first_one() {
 			Some(n) => {
 				//  Split on the far side of the found index. This is always
 				//  safe, as split(len) yields (self, empty).
@@ -2411,7 +2412,7 @@ where
 	T: BitStore,
 {
 	fn next_back(&mut self) -> Option<Self::Item> {
-		match self.inner.iter().copied().rposition(|b| b) {
+		match self.inner.last_one() {
 			Some(n) => {
 				let (rest, _) = unsafe { self.inner.split_at_unchecked(n) };
 				self.inner = rest;
@@ -2495,7 +2496,7 @@ where
 	type Item = usize;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		match self.inner.iter().copied().position(|b| !b) {
+		match self.inner.first_zero() {
 			Some(n) => {
 				let (_, rest) = unsafe { self.inner.split_at_unchecked(n + 1) };
 				self.inner = rest;
@@ -2530,7 +2531,7 @@ where
 	T: BitStore,
 {
 	fn next_back(&mut self) -> Option<Self::Item> {
-		match self.inner.iter().copied().rposition(|b| !b) {
+		match self.inner.last_zero() {
 			Some(n) => {
 				let (rest, _) = unsafe { self.inner.split_at_unchecked(n) };
 				self.inner = rest;
@@ -2561,6 +2562,311 @@ where
 {
 }
 
+/** Enumerates the maximal runs of identical bits in a [`BitSlice`].
+
+This struct is created by the [`.iter_runs()`] method on [`BitSlice`]s.
+
+[`BitSlice`]: crate::slice::BitSlice
+[`.iter_runs()`]: crate::slice::BitSlice::iter_runs
+**/
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct IterRuns<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The remaining slice whose runs are to be found.
+	inner: &'a BitSlice<O, T>,
+	/// The offset from the front of the original slice to current `inner`.
+	front: usize,
+}
+
+impl<'a, O, T> IterRuns<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	pub(crate) fn new(slice: &'a BitSlice<O, T>) -> Self {
+		Self {
+			inner: slice,
+			front: 0,
+		}
+	}
+}
+
+impl<O, T> Default for IterRuns<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn default() -> Self {
+		Self {
+			inner: Default::default(),
+			front: 0,
+		}
+	}
+}
+
+impl<O, T> Iterator for IterRuns<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Item = (bool, Range<usize>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let bit = *self.inner.first()?;
+		let end = if bit {
+			self.inner.first_zero()
+		}
+		else {
+			self.inner.first_one()
+		}
+		.unwrap_or_else(|| self.inner.len());
+		let (_, rest) = unsafe { self.inner.split_at_unchecked(end) };
+		self.inner = rest;
+		let start = self.front;
+		self.front += end;
+		Some((bit, start .. self.front))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.inner.len();
+		(cmp::min(len, 1), Some(len))
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<O, T> DoubleEndedIterator for IterRuns<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let bit = *self.inner.last()?;
+		let start = if bit {
+			self.inner.last_zero()
+		}
+		else {
+			self.inner.last_one()
+		}
+		.map_or(0, |n| n + 1);
+		let end = self.inner.len();
+		let (rest, _) = unsafe { self.inner.split_at_unchecked(start) };
+		self.inner = rest;
+		Some((bit, self.front + start .. self.front + end))
+	}
+}
+
+impl<O, T> FusedIterator for IterRuns<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+/** Enumerates the maximal runs of bits in a [`BitSlice`] that are set to `1`.
+
+This struct is created by the [`.iter_one_ranges()`] method on [`BitSlice`]s.
+
+[`BitSlice`]: crate::slice::BitSlice
+[`.iter_one_ranges()`]: crate::slice::BitSlice::iter_one_ranges
+**/
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct IterOneRanges<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The remaining slice whose runs of `1` bits are to be found.
+	inner: &'a BitSlice<O, T>,
+	/// The offset from the front of the original slice to current `inner`.
+	front: usize,
+}
+
+impl<'a, O, T> IterOneRanges<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	pub(crate) fn new(slice: &'a BitSlice<O, T>) -> Self {
+		Self {
+			inner: slice,
+			front: 0,
+		}
+	}
+}
+
+impl<O, T> Default for IterOneRanges<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn default() -> Self {
+		Self {
+			inner: Default::default(),
+			front: 0,
+		}
+	}
+}
+
+impl<O, T> Iterator for IterOneRanges<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Item = Range<usize>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let start = match self.inner.first_one() {
+			Some(n) => n,
+			None => {
+				*self = Default::default();
+				return None;
+			},
+		};
+		let (_, rest) = unsafe { self.inner.split_at_unchecked(start) };
+		let len = rest.first_zero().unwrap_or_else(|| rest.len());
+		let (_, rest) = unsafe { rest.split_at_unchecked(len) };
+		self.inner = rest;
+		let start = self.front + start;
+		self.front = start + len;
+		Some(start .. self.front)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.inner.len();
+		(0, Some(len - len / 2))
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<O, T> DoubleEndedIterator for IterOneRanges<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let end = match self.inner.last_one() {
+			Some(n) => n + 1,
+			None => {
+				*self = Default::default();
+				return None;
+			},
+		};
+		let (rest, _) = unsafe { self.inner.split_at_unchecked(end) };
+		let start = rest.last_zero().map_or(0, |n| n + 1);
+		let (rest, _) = unsafe { rest.split_at_unchecked(start) };
+		self.inner = rest;
+		Some(self.front + start .. self.front + end)
+	}
+}
+
+impl<O, T> FusedIterator for IterOneRanges<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+/** Enumerates the positions of a bit-pattern in a [`BitSlice`].
+
+This struct is created by the [`.match_indices()`] method on [`BitSlice`]s.
+
+[`BitSlice`]: crate::slice::BitSlice
+[`.match_indices()`]: crate::slice::BitSlice::match_indices
+**/
+#[derive(Clone, Debug)]
+pub struct MatchIndices<'a, O, T, O2, T2>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	/// The remaining slice to be searched.
+	inner: &'a BitSlice<O, T>,
+	/// The pattern being sought.
+	needle: &'a BitSlice<O2, T2>,
+	/// The offset from the front of the original slice to current `inner`.
+	front: usize,
+	/// Marks that the search has been exhausted.
+	done: bool,
+}
+
+impl<'a, O, T, O2, T2> MatchIndices<'a, O, T, O2, T2>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	pub(crate) fn new(
+		slice: &'a BitSlice<O, T>,
+		needle: &'a BitSlice<O2, T2>,
+	) -> Self
+	{
+		Self {
+			inner: slice,
+			needle,
+			front: 0,
+			done: false,
+		}
+	}
+}
+
+impl<O, T, O2, T2> Iterator for MatchIndices<'_, O, T, O2, T2>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	type Item = usize;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		match self.inner.find(self.needle) {
+			Some(n) => {
+				let out = self.front + n;
+				//  Resume after the end of the match. An empty pattern matches
+				//  at every index, including the end of the slice, and so must
+				//  step forward manually.
+				let skip = n + cmp::max(self.needle.len(), 1);
+				if skip > self.inner.len() {
+					self.done = true;
+				}
+				else {
+					self.inner = unsafe { self.inner.get_unchecked(skip ..) };
+					self.front += skip;
+				}
+				Some(out)
+			},
+			None => {
+				self.done = true;
+				None
+			},
+		}
+	}
+}
+
+impl<O, T, O2, T2> FusedIterator for MatchIndices<'_, O, T, O2, T2>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+}
+
 /* This macro has some very obnoxious call syntax that is necessary to handle
 the different iteration protocols used above.
 
@@ -0,0 +1,463 @@
+//! Element-aligned splitting, and bulk queries spread across threads.
+
+use crate::{
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::{
+	iter::FusedIterator,
+	mem,
+};
+
+#[cfg(feature = "std")]
+use std::{
+	panic::{
+		self,
+		AssertUnwindSafe,
+	},
+	thread,
+	vec::Vec,
+};
+
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Splits the slice into at most `parts` mutable sub-slices that do not
+	/// share any memory elements.
+	///
+	/// Unlike [`.chunks_mut()`], every boundary between yielded sub-slices
+	/// falls on the edge of a memory element, so the sub-slices do not need an
+	/// alias marker and keep the `T` type parameter. They can be handed to
+	/// separate threads and written without atomic instructions.
+	///
+	/// The memory elements under `self` are divided as evenly as possible,
+	/// so the first and last sub-slices may be shorter than the others when
+	/// `self` begins or ends partway through an element.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `parts`: The maximum number of sub-slices to produce.
+	///
+	/// # Returns
+	///
+	/// An iterator over non-empty, non-overlapping sub-slices that together
+	/// cover `self`.
+	///
+	/// # Panics
+	///
+	/// This panics if `parts` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 5];
+	/// let bits = &mut data.view_bits_mut::<Msb0>()[4 ..];
+	/// let parts: Vec<&mut BitSlice<Msb0, u8>> = bits.par_chunks_mut(2).collect();
+	/// assert_eq!(parts.len(), 2);
+	/// assert_eq!(parts[0].len(), 20);
+	/// assert_eq!(parts[1].len(), 16);
+	///
+	/// for part in parts {
+	///   part.set_all(true);
+	/// }
+	/// assert_eq!(data, [0x0F, 0xFF, 0xFF, 0xFF, 0xFF]);
+	/// ```
+	///
+	/// [`.chunks_mut()`]: Self::chunks_mut
+	pub fn par_chunks_mut(&mut self, parts: usize) -> ParChunksMut<'_, O, T> {
+		assert!(parts != 0, "Cannot split a slice into zero parts");
+		let bitptr = self.bitptr();
+		let elts = bitptr.elements();
+		let step = div_ceil(elts, parts) * T::Mem::BITS as usize;
+		let head = bitptr.head().value() as usize;
+		ParChunksMut {
+			slice: self,
+			cut: step.saturating_sub(head),
+			step,
+		}
+	}
+
+	/// Splits a mutable slice at an index that falls on a memory element edge.
+	///
+	/// This behaves like [`.split_at_mut()`], except that when the split does
+	/// not divide any memory element, the halves keep the `T` type parameter
+	/// rather than receiving an alias marker.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `mid`: The index at which to split. It must be `0`, `self.len()`, or
+	///   an index at which a new memory element begins.
+	///
+	/// # Returns
+	///
+	/// The halves `[0, mid)` and `[mid, len)`, or `None` if `mid` falls inside
+	/// a memory element.
+	///
+	/// # Panics
+	///
+	/// This panics if `mid` is greater than `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u64; 2];
+	/// let bits = data.view_bits_mut::<Lsb0>();
+	/// assert!(bits.split_at_aligned_mut(10).is_none());
+	///
+	/// let (left, right): (&mut BitSlice<Lsb0, u64>, _) =
+	///   bits.split_at_aligned_mut(64).unwrap();
+	/// left.set(0, true);
+	/// right.set(0, true);
+	/// assert_eq!(data, [1, 1]);
+	/// ```
+	///
+	/// [`.split_at_mut()`]: Self::split_at_mut
+	pub fn split_at_aligned_mut(
+		&mut self,
+		mid: usize,
+	) -> Option<(&mut Self, &mut Self)>
+	{
+		let len = self.len();
+		assert!(mid <= len, "Index out of range: {} > {}", mid, len);
+		let head = self.bitptr().head().value() as usize;
+		let edge = (head + mid) & (T::Mem::BITS as usize - 1) == 0;
+		if mid == 0 || mid == len || edge {
+			Some(unsafe { self.split_at_unchecked_mut_noalias(mid) })
+		}
+		else {
+			None
+		}
+	}
+
+	/// Divides the slice into mutable chunks of whole memory elements.
+	///
+	/// This behaves like [`.chunks_exact_mut()`], except that because every
+	/// chunk begins and ends on an element edge, the chunks and the remainder
+	/// keep the `T` type parameter rather than receiving an alias marker.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `chunk_size`: The length of each chunk. It must be a multiple of the
+	///   bit width of `T`.
+	///
+	/// # Returns
+	///
+	/// An iterator over the chunks, or `None` if `chunk_size` is not a
+	/// multiple of the element width or `self` does not begin on an element
+	/// edge.
+	///
+	/// # Panics
+	///
+	/// This panics if `chunk_size` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 5];
+	/// let bits = data.view_bits_mut::<Msb0>();
+	/// assert!(bits.chunks_exact_aligned_mut(12).is_none());
+	/// assert!(bits[1 ..].chunks_exact_aligned_mut(16).is_none());
+	///
+	/// let mut chunks = bits.chunks_exact_aligned_mut(16).unwrap();
+	/// for chunk in &mut chunks {
+	///   chunk.set(0, true);
+	/// }
+	/// chunks.into_remainder().set_all(true);
+	/// assert_eq!(data, [0x80, 0, 0x80, 0, 0xFF]);
+	/// ```
+	///
+	/// [`.chunks_exact_mut()`]: Self::chunks_exact_mut
+	pub fn chunks_exact_aligned_mut(
+		&mut self,
+		chunk_size: usize,
+	) -> Option<ChunksExactAlignedMut<'_, O, T>>
+	{
+		assert!(chunk_size != 0, "Chunk width cannot be 0");
+		let mask = T::Mem::BITS as usize - 1;
+		if chunk_size & mask != 0 || self.bitptr().head().value() != 0 {
+			return None;
+		}
+		let len = self.len();
+		let (slice, extra) = unsafe {
+			self.split_at_unchecked_mut_noalias(len - len % chunk_size)
+		};
+		Some(ChunksExactAlignedMut {
+			slice,
+			extra,
+			width: chunk_size,
+		})
+	}
+}
+
+#[cfg(feature = "std")]
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore + Sync,
+{
+	/// Counts the bits set to `1`, spreading the work across threads.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `threads`: The number of threads to use. Values of `0` or `1` count
+	///   on the calling thread.
+	///
+	/// # Returns
+	///
+	/// The same value as [`.count_ones()`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![1; 1000];
+	/// assert_eq!(bv.par_count_ones(4), 1000);
+	/// ```
+	///
+	/// [`.count_ones()`]: Self::count_ones
+	pub fn par_count_ones(&self, threads: usize) -> usize {
+		fan_out(self, threads, Self::count_ones).into_iter().sum()
+	}
+
+	/// Tests if any bit is set to `1`, spreading the work across threads.
+	///
+	/// See [`.par_count_ones()`] for the meaning of `threads`.
+	///
+	/// [`.par_count_ones()`]: Self::par_count_ones
+	pub fn par_any(&self, threads: usize) -> bool {
+		fan_out(self, threads, Self::any).into_iter().any(|b| b)
+	}
+
+	/// Tests if every bit is set to `1`, spreading the work across threads.
+	///
+	/// See [`.par_count_ones()`] for the meaning of `threads`.
+	///
+	/// [`.par_count_ones()`]: Self::par_count_ones
+	pub fn par_all(&self, threads: usize) -> bool {
+		fan_out(self, threads, Self::all).into_iter().all(|b| b)
+	}
+}
+
+/** Mutable, element-aligned sub-slices of a [`BitSlice`].
+
+This type is produced by [`BitSlice::par_chunks_mut`].
+
+[`BitSlice`]: crate::slice::BitSlice
+[`BitSlice::par_chunks_mut`]: crate::slice::BitSlice::par_chunks_mut
+**/
+#[derive(Debug)]
+pub struct ParChunksMut<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The remaining region.
+	slice: &'a mut BitSlice<O, T>,
+	/// The length of the next sub-slice, which ends on an element edge.
+	cut: usize,
+	/// The length of all sub-slices after the first.
+	step: usize,
+}
+
+impl<'a, O, T> Iterator for ParChunksMut<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Item = &'a mut BitSlice<O, T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.slice.is_empty() {
+			return None;
+		}
+		let slice = mem::replace(&mut self.slice, BitSlice::empty_mut());
+		if self.cut >= slice.len() {
+			return Some(slice);
+		}
+		//  The cut falls on an element edge, so the halves share no element.
+		let (head, rest) =
+			unsafe { slice.split_at_unchecked_mut_noalias(self.cut) };
+		self.slice = rest;
+		self.cut = self.step;
+		Some(head)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.slice.len();
+		let count = match len {
+			0 => 0,
+			n if n <= self.cut => 1,
+			n => 1 + div_ceil(n - self.cut, self.step),
+		};
+		(count, Some(count))
+	}
+}
+
+impl<O, T> ExactSizeIterator for ParChunksMut<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+impl<O, T> FusedIterator for ParChunksMut<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+/** Mutable chunks of whole memory elements of a [`BitSlice`].
+
+This type is produced by [`BitSlice::chunks_exact_aligned_mut`].
+
+[`BitSlice`]: crate::slice::BitSlice
+[`BitSlice::chunks_exact_aligned_mut`]: crate::slice::BitSlice::chunks_exact_aligned_mut
+**/
+#[derive(Debug)]
+pub struct ChunksExactAlignedMut<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The region not yet yielded, which is a whole number of chunks.
+	slice: &'a mut BitSlice<O, T>,
+	/// The trailing region shorter than a chunk.
+	extra: &'a mut BitSlice<O, T>,
+	/// The length of each chunk.
+	width: usize,
+}
+
+impl<'a, O, T> ChunksExactAlignedMut<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Produces the trailing region that is too short to form a chunk.
+	pub fn into_remainder(self) -> &'a mut BitSlice<O, T> {
+		self.extra
+	}
+}
+
+impl<'a, O, T> Iterator for ChunksExactAlignedMut<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Item = &'a mut BitSlice<O, T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.slice.is_empty() {
+			return None;
+		}
+		let slice = mem::replace(&mut self.slice, BitSlice::empty_mut());
+		let (head, rest) =
+			unsafe { slice.split_at_unchecked_mut_noalias(self.width) };
+		self.slice = rest;
+		Some(head)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.slice.len() / self.width;
+		(len, Some(len))
+	}
+}
+
+impl<O, T> DoubleEndedIterator for ChunksExactAlignedMut<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.slice.is_empty() {
+			return None;
+		}
+		let slice = mem::replace(&mut self.slice, BitSlice::empty_mut());
+		let mid = slice.len() - self.width;
+		let (rest, tail) = unsafe { slice.split_at_unchecked_mut_noalias(mid) };
+		self.slice = rest;
+		Some(tail)
+	}
+}
+
+impl<O, T> ExactSizeIterator for ChunksExactAlignedMut<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+impl<O, T> FusedIterator for ChunksExactAlignedMut<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+/// Divides, rounding up.
+fn div_ceil(num: usize, den: usize) -> usize {
+	if num == 0 {
+		return 0;
+	}
+	(num - 1) / den + 1
+}
+
+/// Runs a query over up to `threads` sub-slices of `bits`, each on its own
+/// thread, and collects the results in order.
+///
+/// Every spawned thread is joined before this returns, including when a query
+/// panics, so the borrowed sub-slices cannot outlive `bits`. A sub-slice whose
+/// thread cannot be spawned is queried on the calling thread.
+#[cfg(feature = "std")]
+fn fan_out<O, T, R>(
+	bits: &BitSlice<O, T>,
+	threads: usize,
+	work: fn(&BitSlice<O, T>) -> R,
+) -> Vec<R>
+where
+	O: BitOrder,
+	T: BitStore + Sync,
+	R: Send + 'static,
+{
+	let len = bits.len();
+	if threads <= 1 || len == 0 {
+		return vec![work(bits)];
+	}
+	let chunk = div_ceil(len, threads);
+	let handles = bits
+		.chunks(chunk)
+		.map(|part| {
+			let part: &'static BitSlice<O, T> = unsafe { &*(part as *const _) };
+			thread::Builder::new()
+				.spawn(move || work(part))
+				.map_err(|_| part)
+		})
+		.collect::<Vec<_>>();
+	let results = handles
+		.into_iter()
+		.map(|handle| match handle {
+			Ok(handle) => handle.join(),
+			Err(part) => panic::catch_unwind(AssertUnwindSafe(|| work(part))),
+		})
+		.collect::<Vec<_>>();
+	results
+		.into_iter()
+		.map(|res| res.unwrap_or_else(|err| panic::resume_unwind(err)))
+		.collect()
+}
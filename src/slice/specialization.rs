@@ -114,6 +114,24 @@ where T: BitStore
 			.zip(other.chunks(chunk_size))
 			.all(|(a, b)| a.load_le::<usize>() == b.load_le::<usize>())
 	}
+
+	/// Accelerates ordered loads by using the little-endian batch load, which
+	/// places the bit at index `0` in the least significant position.
+	pub(crate) fn sp_load_ordered(&self) -> usize {
+		if self.is_empty() {
+			return 0;
+		}
+		self.load_le::<usize>()
+	}
+
+	/// Accelerates ordered stores by using the little-endian batch store,
+	/// which takes the bit at index `0` from the least significant position.
+	pub(crate) fn sp_store_ordered(&mut self, value: usize) {
+		if self.is_empty() {
+			return;
+		}
+		self.store_le::<usize>(value);
+	}
 }
 
 /** Order-specialized function implementations.
@@ -190,4 +208,27 @@ where T: BitStore
 			.zip(other.chunks(chunk_size))
 			.all(|(a, b)| a.load_be::<usize>() == b.load_be::<usize>())
 	}
+
+	/// Accelerates ordered loads by reversing the big-endian batch load, which
+	/// places the bit at index `0` in the most significant position.
+	pub(crate) fn sp_load_ordered(&self) -> usize {
+		if self.is_empty() {
+			return 0;
+		}
+		self.load_be::<usize>().reverse_bits()
+			>> (<usize as BitMemory>::BITS as usize - self.len())
+	}
+
+	/// Accelerates ordered stores by reversing the value for the big-endian
+	/// batch store, which takes the bit at index `0` from the most significant
+	/// position.
+	pub(crate) fn sp_store_ordered(&mut self, value: usize) {
+		if self.is_empty() {
+			return;
+		}
+		self.store_be::<usize>(
+			value.reverse_bits()
+				>> (<usize as BitMemory>::BITS as usize - self.len()),
+		);
+	}
 }
@@ -196,6 +196,48 @@ fn query() {
 	assert!(bits![0, 1, 0].ends_with(bits![1, 0]));
 }
 
+#[test]
+fn scan() {
+	fn check<O, T>(bits: &BitSlice<O, T>)
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let len = bits.len();
+		let first = |val: bool| bits.iter().position(|b| *b == val);
+		let last = |val: bool| bits.iter().rposition(|b| *b == val);
+		assert_eq!(bits.first_one(), first(true));
+		assert_eq!(bits.first_zero(), first(false));
+		assert_eq!(bits.last_one(), last(true));
+		assert_eq!(bits.last_zero(), last(false));
+		assert_eq!(bits.leading_zeros(), first(true).unwrap_or(len));
+		assert_eq!(bits.leading_ones(), first(false).unwrap_or(len));
+		assert_eq!(
+			bits.trailing_zeros(),
+			last(true).map_or(len, |idx| len - 1 - idx)
+		);
+		assert_eq!(
+			bits.trailing_ones(),
+			last(false).map_or(len, |idx| len - 1 - idx)
+		);
+	}
+
+	let data = [0u16, 0x0100, 0, 0, 0xFFFF, 0xFEFF, 0x8000, 0];
+	for (start, end) in
+		[(0, 128), (3, 7), (9, 23), (20, 90), (64, 100), (70, 80), (0, 0)]
+			.iter()
+			.copied()
+	{
+		check(&data.view_bits::<Lsb0>()[start .. end]);
+		check(&data.view_bits::<Msb0>()[start .. end]);
+	}
+
+	assert_eq!(bits![0, 0, 1, 1, 0].leading_zeros(), 2);
+	assert_eq!(bits![0, 0, 1, 1, 0].trailing_zeros(), 1);
+	assert_eq!(bits![1, 1, 1, 0, 1].leading_ones(), 3);
+	assert_eq!(bits![1, 0, 1, 1, 1].trailing_ones(), 3);
+}
+
 #[test]
 fn modify() {
 	let mut data = 0b0000_1111u8;
@@ -447,6 +489,69 @@ fn rotate() {
 	assert_eq!(bits, bits![0, 1, 0, 0, 1, 0]);
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn search() {
+	//  A pseudo-random haystack, so that partial matches are common.
+	let mut state = 0x2545_F491u32;
+	let mut data = [0u16; 40];
+	for elem in data.iter_mut() {
+		state ^= state << 13;
+		state ^= state >> 17;
+		state ^= state << 5;
+		*elem = state as u16 & (state >> 16) as u16;
+	}
+
+	fn naive<O, T, O2, T2>(
+		hay: &BitSlice<O, T>,
+		needle: &BitSlice<O2, T2>,
+	) -> Vec<usize>
+	where
+		O: BitOrder,
+		T: BitStore,
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		hay.windows(needle.len())
+			.enumerate()
+			.filter(|(_, w)| w == &needle)
+			.map(|(idx, _)| idx)
+			.collect()
+	}
+
+	let lsb = &data.view_bits::<Lsb0>()[3 ..];
+	let msb = &data.view_bits::<Msb0>()[5 .. 630];
+	for &(start, width) in
+		&[(0, 1), (7, 3), (40, 9), (100, 17), (200, 64), (301, 65), (450, 130)]
+	{
+		let mut needle = bitvec![Msb0, u8; 0; width];
+		needle.clone_from_bitslice(&lsb[start .. start + width]);
+		let all = naive(lsb, &needle);
+		assert_eq!(lsb.find(&needle), all.first().copied());
+		assert_eq!(lsb.rfind(&needle), all.last().copied());
+		assert!(all.contains(&start));
+
+		let all = naive(msb, &needle);
+		assert_eq!(msb.find(&needle), all.first().copied());
+		assert_eq!(msb.rfind(&needle), all.last().copied());
+
+		let mut matches = msb.match_indices(&needle[..]);
+		if let Some(&first) = all.first() {
+			assert_eq!(matches.next(), Some(first));
+		}
+	}
+
+	let bits = bits![0, 1, 0, 1, 0, 1, 0];
+	assert_eq!(
+		bits.match_indices(bits![0, 1, 0]).collect::<Vec<_>>(),
+		[0, 4]
+	);
+	assert_eq!(bits.match_indices(bits![]).count(), 8);
+	assert!(bits.find(bits![1, 1]).is_none());
+	assert!(bits.rfind(bits![1, 1]).is_none());
+	assert!(bits[.. 2].find(bits![0, 1, 0]).is_none());
+}
+
 #[test]
 fn unspecialized() {
 	use crate::{
@@ -484,6 +589,11 @@ fn unspecialized() {
 	//  Dodge the memcpy accelerant
 	bits[.. 8].copy_from_bitslice(&bits![Swizzle, u8; 0; 9][1 ..]);
 	assert_eq!(bits, [0u8, !0].view_bits::<Swizzle>());
+	assert_eq!(bits.find(bits![1, 1]), Some(8));
+	assert_eq!(bits.rfind(bits![0, 1]), Some(7));
+	assert_eq!(bits.first_one(), Some(8));
+	assert_eq!(bits.last_zero(), Some(7));
+	assert_eq!(bits[4 .. 12].trailing_ones(), 4);
 }
 
 #[test]
@@ -773,6 +883,205 @@ fn iter_ones_zeros() {
 	assert!(zeros.nth_back(0).is_none());
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn iter_runs() {
+	let mut data = [0u32; 5];
+	let bits = &mut data.view_bits_mut::<Msb0>()[5 .. 150];
+	for idx in (10 .. 80).chain(95 .. 96).chain(100 .. 145) {
+		bits.set(idx, true);
+	}
+	let bits = &*bits;
+
+	let runs = bits.iter_runs().collect::<Vec<_>>();
+	assert_eq!(runs, [
+		(false, 0 .. 10),
+		(true, 10 .. 80),
+		(false, 80 .. 95),
+		(true, 95 .. 96),
+		(false, 96 .. 100),
+		(true, 100 .. 145),
+	]);
+	let mut back = bits.iter_runs().rev().collect::<Vec<_>>();
+	back.reverse();
+	assert_eq!(back, runs);
+
+	let ones = bits.iter_one_ranges().collect::<Vec<_>>();
+	assert_eq!(ones, [10 .. 80, 95 .. 96, 100 .. 145]);
+	let mut ranges = bits.iter_one_ranges();
+	assert_eq!(ranges.next_back(), Some(100 .. 145));
+	assert_eq!(ranges.next(), Some(10 .. 80));
+	assert_eq!(ranges.next_back(), Some(95 .. 96));
+	assert!(ranges.next().is_none());
+	assert!(ranges.next_back().is_none());
+
+	let rebuilt = BitVec::<Lsb0, u8>::from_runs(
+		runs.iter().map(|(bit, range)| (*bit, range.len())),
+	);
+	assert_eq!(rebuilt, bits);
+	assert!(BitSlice::<Lsb0, u8>::empty().iter_runs().next().is_none());
+	assert!(bits![0; 20].iter_one_ranges().next().is_none());
+}
+
+#[test]
+fn par_chunks_mut() {
+	let mut data = [0u16; 10];
+	let bits = &mut data.view_bits_mut::<Lsb0>()[3 .. 150];
+
+	for parts in 1 .. 14 {
+		let chunks = bits.par_chunks_mut(parts);
+		let count = chunks.len();
+		let mut seen = 0;
+		let mut total = 0;
+		for chunk in chunks {
+			seen += 1;
+			total += chunk.len();
+			assert!(!chunk.is_empty());
+			assert!(chunk.bitptr().elements() <= 10 / parts + (10 % parts != 0) as usize);
+		}
+		assert_eq!(seen, count);
+		assert!(count <= parts);
+		assert_eq!(total, 147);
+	}
+
+	let mut iter = bits.par_chunks_mut(3);
+	assert_eq!(iter.next().unwrap().len(), 61);
+	assert_eq!(iter.next().unwrap().len(), 64);
+	assert_eq!(iter.next().unwrap().len(), 22);
+	assert!(iter.next().is_none());
+
+	for (idx, chunk) in bits.par_chunks_mut(4).enumerate() {
+		chunk.set_all(idx % 2 == 0);
+	}
+	assert_eq!(data[0], 0xFFF8);
+	assert_eq!(data[3], 0);
+	assert!(BitSlice::<Lsb0, u8>::empty_mut().par_chunks_mut(4).next().is_none());
+}
+
+#[test]
+fn split_at_aligned_mut() {
+	let mut data = [0u32; 3];
+	let bits = &mut data.view_bits_mut::<Msb0>()[8 ..];
+
+	assert!(bits.split_at_aligned_mut(8).is_none());
+	assert!(bits.split_at_aligned_mut(25).is_none());
+	let (left, right) = bits.split_at_aligned_mut(24).unwrap();
+	assert_eq!((left.len(), right.len()), (24, 64));
+	left.set_all(true);
+	right.set(0, true);
+	let (left, right) = bits.split_at_aligned_mut(0).unwrap();
+	assert!(left.is_empty());
+	assert_eq!(right.len(), 88);
+	assert!(bits.split_at_aligned_mut(88).is_some());
+	assert_eq!(data, [0x00FF_FFFF, 0x8000_0000, 0]);
+
+	let bits = data.view_bits_mut::<Lsb0>();
+	assert!(bits.chunks_exact_aligned_mut(48).is_none());
+	assert!(bits[32 ..].chunks_exact_aligned_mut(64).unwrap().len() == 1);
+	let mut chunks = bits[.. 95].chunks_exact_aligned_mut(32).unwrap();
+	assert_eq!(chunks.len(), 2);
+	chunks.next_back().unwrap().set_all(true);
+	chunks.next().unwrap().set_all(false);
+	assert!(chunks.next().is_none());
+	assert_eq!(chunks.into_remainder().len(), 31);
+	assert_eq!(data, [0, !0, 0]);
+}
+
+#[test]
+fn fetch_bits() {
+	use core::{
+		cell::Cell,
+		sync::atomic::Ordering::*,
+	};
+
+	let data = [Cell::new(0u8), Cell::new(0), Cell::new(0)];
+	let bits = BitSlice::<Lsb0, _>::from_slice(&data).unwrap();
+
+	assert!(!bits.fetch_toggle(9, Relaxed));
+	assert!(bits.fetch_toggle(9, Relaxed));
+	assert!(!bits.fetch_set(9, Relaxed));
+	assert!(bits.fetch_clear(9, Relaxed));
+	assert!(!bits.fetch_clear(9, Relaxed));
+
+	assert_eq!(
+		bits.compare_exchange_bit(3, true, false, Relaxed, Relaxed),
+		Err(false)
+	);
+	assert_eq!(
+		bits.compare_exchange_bit(3, false, true, Relaxed, Relaxed),
+		Ok(false)
+	);
+	assert_eq!(data[0].get(), 0x08);
+
+	assert_eq!(bits.fetch_or_range(2 .. 5, Relaxed), 1);
+	assert_eq!(bits.fetch_or_range(4 .., Relaxed), 1);
+	assert!(bits[2 ..].all());
+	assert_eq!(bits.fetch_and_range(1 .. 23, Relaxed), 21);
+	assert_eq!(bits.fetch_and_range(.., Relaxed), 1);
+	assert!(bits.not_any());
+	assert_eq!(bits.fetch_or_range(5 .. 5, Relaxed), 0);
+}
+
+#[test]
+#[cfg(all(feature = "atomic", feature = "std"))]
+fn fetch_bits_threaded() {
+	use core::sync::atomic::{
+		AtomicU32,
+		Ordering::*,
+	};
+	use std::{
+		sync::Arc,
+		thread,
+	};
+
+	let data = Arc::new([AtomicU32::new(0), AtomicU32::new(0)]);
+	let handles = (0 .. 4)
+		.map(|id| {
+			let data = data.clone();
+			thread::spawn(move || {
+				let bits = BitSlice::<Msb0, _>::from_slice(&*data).unwrap();
+				let mut won = 0;
+				for round in 0 .. 1000 {
+					//  Every thread contends for the same slots in the first
+					//  word, while toggling its own bit in the second.
+					let idx = round % 32;
+					while bits.compare_exchange_bit(idx, false, true, AcqRel, Acquire)
+						!= Ok(false)
+					{
+						thread::yield_now();
+					}
+					won += 1;
+					assert!(bits.fetch_clear(idx, AcqRel));
+					bits.fetch_toggle(32 + id, AcqRel);
+				}
+				won
+			})
+		})
+		.collect::<Vec<_>>();
+	let won: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+	assert_eq!(won, 4000);
+	let bits = BitSlice::<Msb0, _>::from_slice(&*data).unwrap();
+	assert!(bits.not_any());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn par_queries() {
+	let mut bv = bitvec![Msb0, u32; 0; 1000];
+	for threads in 0 .. 6 {
+		assert!(!bv.par_any(threads));
+		assert_eq!(bv.par_count_ones(threads), 0);
+	}
+	bv.set(777, true);
+	assert!(bv.par_any(3));
+	assert!(!bv.par_all(3));
+	assert_eq!(bv[5 ..].par_count_ones(7), 1);
+	bv.set_all(true);
+	assert!(bv.par_all(4));
+	assert_eq!(bv[1 .. 999].par_count_ones(16), 998);
+	assert!(BitSlice::<Lsb0, u8>::empty().par_all(2));
+}
+
 #[cfg(feature = "alloc")]
 mod format {
 	use crate::prelude::*;
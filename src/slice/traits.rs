@@ -42,6 +42,16 @@ use crate::vec::BitVec;
 #[cfg(feature = "alloc")]
 use alloc::borrow::ToOwned;
 
+impl<O, T> AsRef<Self> for BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn as_ref(&self) -> &Self {
+		self
+	}
+}
+
 impl<O, T> Eq for BitSlice<O, T>
 where
 	O: BitOrder,
@@ -307,6 +307,12 @@ store!(u64 => BitSafeU64);
 
 store!(usize => BitSafeUsize);
 
+/* `u128` is deliberately not a storage type. `BitPtr` requires each element to
+be aligned to its size, but `u128` is only 16-byte aligned on x86_64 as of Rust
+1.77, well after this crate’s minimum supported version of 1.47, and `radium`
+0.6 provides no 128-bit alias or atomic types.
+*/
+
 radium::if_atomic! {
 	if atomic(8) {
 		use core::sync::atomic;
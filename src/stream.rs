@@ -0,0 +1,657 @@
+/*! Bit-granular stream cursors.
+
+The [`Read`] and [`Write`] implementations in the `field` module move data in
+whole bytes, which is insufficient for protocols whose fields have arbitrary
+bit widths. This module provides a [`BitReader`] cursor over a borrowed
+[`BitSlice`], and a [`BitWriter`] cursor that appends to a [`BitVec`], which
+transfer integers of any width up to the width of the integer type.
+
+Both cursors use the [`BitField`] trait to move values into and out of memory,
+and so are only able to operate on slices that implement it. Rather than
+panicking when a request cannot be satisfied, they report a [`StreamError`] and
+leave the cursor unchanged.
+
+[`BitField`]: crate::field::BitField
+[`BitReader`]: self::BitReader
+[`BitSlice`]: crate::slice::BitSlice
+[`BitVec`]: crate::vec::BitVec
+[`BitWriter`]: self::BitWriter
+[`Read`]: std::io::Read
+[`StreamError`]: self::StreamError
+[`Write`]: std::io::Write
+!*/
+
+use crate::{
+	field::BitField,
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+
+/** A read cursor over a bit-slice.
+
+The reader holds a shared reference to a [`BitSlice`] and a position within it.
+Each read advances the position past the bits it consumed; peeks inspect the
+same bits without advancing.
+
+# Type Parameters
+
+- `O`: The ordering of bits within elements of the source slice.
+- `T`: The storage type of the source slice.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::stream::BitReader;
+
+let data = [0b1010_0011u8, 0b1100_0000];
+let mut reader = BitReader::new(data.view_bits::<Msb0>());
+
+assert_eq!(reader.read_bits::<u8>(3), Ok(0b101));
+assert_eq!(reader.peek_bits::<u8>(2), Ok(0b00));
+assert_eq!(reader.read_bits::<u16>(7), Ok(0b000_1111));
+assert_eq!(reader.remaining(), 6);
+assert!(reader.read_bits::<u8>(7).is_err());
+```
+
+[`BitSlice`]: crate::slice::BitSlice
+**/
+#[derive(Clone, Copy, Debug)]
+pub struct BitReader<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The complete source slice.
+	bits: &'a BitSlice<O, T>,
+	/// The index in `bits` of the next bit to be read.
+	pos: usize,
+}
+
+impl<'a, O, T> BitReader<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Creates a reader at the start of a bit-slice.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The source of all bits yielded by the reader.
+	///
+	/// # Returns
+	///
+	/// A reader whose position is at index `0` of `bits`.
+	pub fn new(bits: &'a BitSlice<O, T>) -> Self {
+		Self { bits, pos: 0 }
+	}
+
+	/// Gets the number of bits that have been consumed from the source.
+	pub fn position(&self) -> usize {
+		self.pos
+	}
+
+	/// Gets the number of bits that remain to be read from the source.
+	pub fn remaining(&self) -> usize {
+		self.bits.len() - self.pos
+	}
+
+	/// Tests if the reader has consumed its entire source.
+	pub fn is_empty(&self) -> bool {
+		self.remaining() == 0
+	}
+
+	/// Views the bits that remain to be read.
+	pub fn as_bitslice(&self) -> &'a BitSlice<O, T> {
+		unsafe { self.bits.get_unchecked(self.pos ..) }
+	}
+
+	/// Removes the cursor, returning the complete source slice.
+	pub fn into_inner(self) -> &'a BitSlice<O, T> {
+		self.bits
+	}
+
+	/// Views some number of upcoming bits, without consuming them.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `count`: The number of bits to view.
+	///
+	/// # Returns
+	///
+	/// The next `count` bits of the source, or an error if fewer than `count`
+	/// bits remain.
+	pub fn peek_bitslice(
+		&self,
+		count: usize,
+	) -> Result<&'a BitSlice<O, T>, StreamError>
+	{
+		let rest = self.as_bitslice();
+		if count > rest.len() {
+			return Err(StreamError::Underrun {
+				requested: count,
+				remaining: rest.len(),
+			});
+		}
+		Ok(unsafe { rest.get_unchecked(.. count) })
+	}
+
+	/// Consumes some number of bits, returning them as a subslice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `count`: The number of bits to consume.
+	///
+	/// # Returns
+	///
+	/// The next `count` bits of the source, or an error if fewer than `count`
+	/// bits remain. The cursor is not moved when this produces an error.
+	pub fn read_bitslice(
+		&mut self,
+		count: usize,
+	) -> Result<&'a BitSlice<O, T>, StreamError>
+	{
+		let out = self.peek_bitslice(count)?;
+		self.pos += count;
+		Ok(out)
+	}
+
+	/// Consumes a single bit.
+	///
+	/// # Returns
+	///
+	/// The next bit in the source, or an error if the source is exhausted.
+	pub fn read_bit(&mut self) -> Result<bool, StreamError> {
+		self.read_bitslice(1).map(|bits| bits[0])
+	}
+
+	/// Advances the cursor without inspecting the bits it passes.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `count`: The number of bits to skip.
+	///
+	/// # Returns
+	///
+	/// An error if fewer than `count` bits remain. The cursor is not moved when
+	/// this produces an error.
+	pub fn skip(&mut self, count: usize) -> Result<(), StreamError> {
+		self.read_bitslice(count).map(drop)
+	}
+
+	/// Advances the cursor to the next multiple of eight bits from the start of
+	/// the source.
+	///
+	/// This does nothing if the cursor is already aligned.
+	///
+	/// # Returns
+	///
+	/// The number of bits skipped, or an error if the source ends before the
+	/// next byte boundary.
+	pub fn align_to_byte(&mut self) -> Result<usize, StreamError> {
+		let skip = self.pos.wrapping_neg() & 7;
+		self.skip(skip).map(|_| skip)
+	}
+
+	/// Consumes a single bit, returning it back to the source if `func`
+	/// rejects it.
+	///
+	/// This is a convenience for optional flags and sentinel bits.
+	pub fn read_bit_if<F>(&mut self, func: F) -> Result<bool, StreamError>
+	where F: FnOnce(bool) -> bool {
+		let bit = self.peek_bitslice(1)?[0];
+		if func(bit) {
+			self.pos += 1;
+			Ok(true)
+		}
+		else {
+			Ok(false)
+		}
+	}
+}
+
+impl<'a, O, T> BitReader<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Views some number of upcoming bits as an integer, without consuming
+	/// them.
+	///
+	/// The bits are loaded with [`BitField::load_be`]. Under `Msb0` ordering,
+	/// this places the first bit of the stream in the most significant position
+	/// of the result, which is the layout used by most network protocols.
+	///
+	/// # Type Parameters
+	///
+	/// - `M`: The integer type into which bits are loaded.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `count`: The number of bits to load. This may be zero, which produces
+	///   zero.
+	///
+	/// # Returns
+	///
+	/// The next `count` bits, loaded into the least significant bits of an `M`,
+	/// or an error if `count` is wider than `M` or fewer than `count` bits
+	/// remain.
+	///
+	/// [`BitField::load_be`]: crate::field::BitField::load_be
+	pub fn peek_bits<M>(&self, count: usize) -> Result<M, StreamError>
+	where M: BitMemory {
+		self.peek_with(count, BitField::load_be)
+	}
+
+	/// Views some number of upcoming bits as an integer, without consuming
+	/// them.
+	///
+	/// This is the same as [`.peek_bits()`], except that it loads with
+	/// [`BitField::load_le`]. Under `Lsb0` ordering, this places the first bit
+	/// of the stream in the least significant position of the result, which is
+	/// the layout used by formats such as DEFLATE.
+	///
+	/// [`.peek_bits()`]: Self::peek_bits
+	/// [`BitField::load_le`]: crate::field::BitField::load_le
+	pub fn peek_bits_le<M>(&self, count: usize) -> Result<M, StreamError>
+	where M: BitMemory {
+		self.peek_with(count, BitField::load_le)
+	}
+
+	/// Consumes some number of bits as an integer.
+	///
+	/// This has the same behavior as [`.peek_bits()`], and then advances the
+	/// cursor past the loaded bits. The cursor is not moved when this produces
+	/// an error.
+	///
+	/// [`.peek_bits()`]: Self::peek_bits
+	pub fn read_bits<M>(&mut self, count: usize) -> Result<M, StreamError>
+	where M: BitMemory {
+		let out = self.peek_bits(count)?;
+		self.pos += count;
+		Ok(out)
+	}
+
+	/// Consumes some number of bits as an integer.
+	///
+	/// This has the same behavior as [`.peek_bits_le()`], and then advances the
+	/// cursor past the loaded bits. The cursor is not moved when this produces
+	/// an error.
+	///
+	/// [`.peek_bits_le()`]: Self::peek_bits_le
+	pub fn read_bits_le<M>(&mut self, count: usize) -> Result<M, StreamError>
+	where M: BitMemory {
+		let out = self.peek_bits_le(count)?;
+		self.pos += count;
+		Ok(out)
+	}
+
+	/// Checks a load request, then applies a `BitField` loader to it.
+	fn peek_with<M>(
+		&self,
+		count: usize,
+		load: fn(&BitSlice<O, T>) -> M,
+	) -> Result<M, StreamError>
+	where
+		M: BitMemory,
+	{
+		StreamError::check_width::<M>(count)?;
+		let bits = self.peek_bitslice(count)?;
+		if bits.is_empty() {
+			return Ok(M::ZERO);
+		}
+		Ok(load(bits))
+	}
+}
+
+/** An append cursor over a bit-vector.
+
+The writer holds an exclusive reference to a [`BitVec`], and pushes bits onto
+its end. Integer values are stored with the [`BitField`] trait, using the same
+conventions as the corresponding [`BitReader`] methods, so that values written
+by `.write_bits()` are recovered by `.read_bits()`.
+
+# Type Parameters
+
+- `O`: The ordering of bits within elements of the destination vector.
+- `T`: The storage type of the destination vector.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::stream::{BitReader, BitWriter};
+
+let mut bv = BitVec::<Msb0, u8>::new();
+let mut writer = BitWriter::new(&mut bv);
+writer.write_bits(5u8, 3).unwrap();
+writer.write_bit(true);
+writer.write_bits(0x1234u16, 13).unwrap();
+assert_eq!(writer.len(), 17);
+
+let mut reader = BitReader::new(&bv);
+assert_eq!(reader.read_bits::<u8>(3), Ok(5));
+assert_eq!(reader.read_bit(), Ok(true));
+assert_eq!(reader.read_bits::<u16>(13), Ok(0x1234));
+```
+
+[`BitField`]: crate::field::BitField
+[`BitReader`]: self::BitReader
+[`BitVec`]: crate::vec::BitVec
+**/
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct BitWriter<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The destination vector.
+	bits: &'a mut BitVec<O, T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, O, T> BitWriter<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Creates a writer that appends to a bit-vector.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The destination of all bits given to the writer. Its existing
+	///   contents are kept, and new bits are pushed after them.
+	pub fn new(bits: &'a mut BitVec<O, T>) -> Self {
+		Self { bits }
+	}
+
+	/// Gets the total number of bits in the destination vector.
+	pub fn len(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Tests if the destination vector is empty.
+	pub fn is_empty(&self) -> bool {
+		self.bits.is_empty()
+	}
+
+	/// Views the destination vector.
+	pub fn as_bitslice(&self) -> &BitSlice<O, T> {
+		self.bits.as_bitslice()
+	}
+
+	/// Removes the cursor, returning the destination vector.
+	pub fn into_inner(self) -> &'a mut BitVec<O, T> {
+		self.bits
+	}
+
+	/// Appends a single bit.
+	pub fn write_bit(&mut self, value: bool) {
+		self.bits.push(value);
+	}
+
+	/// Appends a sequence of bits, in order.
+	pub fn write_bitslice<O2, T2>(&mut self, bits: &BitSlice<O2, T2>)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let len = self.bits.len();
+		self.bits.resize(len + bits.len(), false);
+		self.bits[len ..].clone_from_bitslice(bits);
+	}
+
+	/// Appends zero bits until the destination is a multiple of eight bits
+	/// long.
+	///
+	/// # Returns
+	///
+	/// The number of bits appended.
+	pub fn align_to_byte(&mut self) -> usize {
+		let pad = self.bits.len().wrapping_neg() & 7;
+		let len = self.bits.len();
+		self.bits.resize(len + pad, false);
+		pad
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, O, T> BitWriter<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Appends the low bits of an integer.
+	///
+	/// The bits are stored with [`BitField::store_be`], and can be recovered
+	/// with [`BitReader::read_bits`].
+	///
+	/// # Type Parameters
+	///
+	/// - `M`: The integer type from which bits are stored.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: An integer whose `count` least significant bits are written.
+	///   Any higher bits are discarded.
+	/// - `count`: The number of bits to write. This may be zero, which writes
+	///   nothing.
+	///
+	/// # Returns
+	///
+	/// An error if `count` is wider than `M`. Nothing is written when this
+	/// produces an error.
+	///
+	/// [`BitField::store_be`]: crate::field::BitField::store_be
+	/// [`BitReader::read_bits`]: crate::stream::BitReader::read_bits
+	pub fn write_bits<M>(
+		&mut self,
+		value: M,
+		count: usize,
+	) -> Result<(), StreamError>
+	where
+		M: BitMemory,
+	{
+		self.write_with(value, count, BitField::store_be)
+	}
+
+	/// Appends the low bits of an integer.
+	///
+	/// This is the same as [`.write_bits()`], except that it stores with
+	/// [`BitField::store_le`], and its values can be recovered with
+	/// [`BitReader::read_bits_le`].
+	///
+	/// [`.write_bits()`]: Self::write_bits
+	/// [`BitField::store_le`]: crate::field::BitField::store_le
+	/// [`BitReader::read_bits_le`]: crate::stream::BitReader::read_bits_le
+	pub fn write_bits_le<M>(
+		&mut self,
+		value: M,
+		count: usize,
+	) -> Result<(), StreamError>
+	where
+		M: BitMemory,
+	{
+		self.write_with(value, count, BitField::store_le)
+	}
+
+	/// Checks a store request, then applies a `BitField` storer to it.
+	fn write_with<M>(
+		&mut self,
+		value: M,
+		count: usize,
+		store: fn(&mut BitSlice<O, T>, M),
+	) -> Result<(), StreamError>
+	where
+		M: BitMemory,
+	{
+		StreamError::check_width::<M>(count)?;
+		if count == 0 {
+			return Ok(());
+		}
+		let len = self.bits.len();
+		self.bits.resize(len + count, false);
+		store(&mut self.bits[len ..], value);
+		Ok(())
+	}
+}
+
+/** The error type for stream cursor operations.
+
+Stream cursors check every request before modifying themselves, and report the
+first check that failed with this type.
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum StreamError {
+	/// A read requested more bits than remain in the source.
+	Underrun {
+		/// The number of bits requested.
+		requested: usize,
+		/// The number of bits remaining in the source.
+		remaining: usize,
+	},
+	/// A transfer requested more bits than fit in the integer type.
+	TooWide {
+		/// The number of bits requested.
+		requested: usize,
+		/// The bit width of the integer type.
+		width: u8,
+	},
+	/// A decoded value is too large for the integer type that receives it.
+	Overflow {
+		/// The bit width of the integer type.
+		width: u8,
+	},
+}
+
+impl StreamError {
+	/// Checks that a bit count fits in an integer type.
+	fn check_width<M>(count: usize) -> Result<(), Self>
+	where M: BitMemory {
+		if count > M::BITS as usize {
+			return Err(StreamError::TooWide {
+				requested: count,
+				width: M::BITS,
+			});
+		}
+		Ok(())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for StreamError {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::Underrun {
+				requested,
+				remaining,
+			} => write!(
+				fmt,
+				"Cannot read {} bits from a stream with {} bits remaining",
+				requested, remaining
+			),
+			Self::TooWide { requested, width } => write!(
+				fmt,
+				"Cannot transfer {} bits through a {}-bit integer",
+				requested, width
+			),
+			Self::Overflow { width } => write!(
+				fmt,
+				"The decoded value does not fit in a {}-bit integer",
+				width
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StreamError {
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn reader() {
+		let data = [0xA5u8, 0x3C, 0x0F];
+		let mut reader = BitReader::new(data.view_bits::<Msb0>());
+
+		assert_eq!(reader.read_bits::<u8>(0), Ok(0));
+		assert_eq!(reader.read_bit(), Ok(true));
+		assert_eq!(reader.read_bits::<u8>(3), Ok(0b010));
+		assert_eq!(reader.align_to_byte(), Ok(4));
+		assert_eq!(reader.position(), 8);
+		assert_eq!(reader.align_to_byte(), Ok(0));
+		assert_eq!(reader.peek_bits::<u16>(12), Ok(0x3C0));
+		assert_eq!(
+			reader.read_bits::<u8>(9),
+			Err(StreamError::TooWide {
+				requested: 9,
+				width: 8
+			})
+		);
+		assert!(reader.skip(2).is_ok());
+		assert_eq!(reader.read_bit_if(|bit| bit), Ok(true));
+		assert_eq!(reader.read_bit_if(|bit| !bit), Ok(false));
+		assert_eq!(reader.remaining(), 13);
+		assert_eq!(
+			reader.read_bits::<u16>(14),
+			Err(StreamError::Underrun {
+				requested: 14,
+				remaining: 13
+			})
+		);
+		assert_eq!(reader.remaining(), 13);
+		assert_eq!(reader.read_bitslice(13).unwrap().count_ones(), 7);
+		assert!(reader.is_empty());
+		assert!(reader.read_bit().is_err());
+		assert_eq!(reader.align_to_byte(), Ok(0));
+
+		let mut reader = BitReader::new(data.view_bits::<Lsb0>());
+		assert_eq!(reader.read_bits_le::<u16>(12), Ok(0xCA5));
+		assert!(reader.align_to_byte().is_ok());
+		assert_eq!(reader.read_bits_le::<u8>(8), Ok(0x0F));
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn writer() {
+		let mut bv = bitvec![Lsb0, u16; 1];
+		let mut writer = BitWriter::new(&mut bv);
+		assert!(writer.write_bits(0xFFu8, 0).is_ok());
+		assert_eq!(writer.align_to_byte(), 7);
+		assert!(writer.write_bits(0x1ABCu16, 13).is_ok());
+		assert!(writer.write_bits_le(0x2Du8, 6).is_ok());
+		assert!(writer.write_bits(0u8, 9).is_err());
+		writer.write_bitslice(bits![Msb0, u8; 1, 0, 1]);
+		assert_eq!(writer.len(), 30);
+
+		let mut reader = BitReader::new(&bv);
+		assert_eq!(reader.read_bits::<u8>(8), Ok(1));
+		assert_eq!(reader.read_bits::<u16>(13), Ok(0x1ABC));
+		assert_eq!(reader.read_bits_le::<u8>(6), Ok(0x2D));
+		assert_eq!(reader.read_bits::<u8>(3), Ok(0b101));
+		assert!(reader.is_empty());
+	}
+}
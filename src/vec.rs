@@ -290,6 +290,43 @@ where
 		out
 	}
 
+	/// Constructs a `BitVec` from a sequence of runs of identical bits.
+	///
+	/// This is the inverse of [`BitSlice::iter_runs`].
+	///
+	/// # Parameters
+	///
+	/// - `runs`: A sequence of `(bit, count)` pairs. Each appends `count`
+	///   copies of `bit` to the vector. Adjacent runs may have the same bit,
+	///   and runs may be empty.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` containing each run in order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let runs = vec![(false, 2), (true, 3), (false, 1)];
+	/// let bv = BitVec::<Lsb0, u8>::from_runs(runs);
+	/// assert_eq!(bv, bits![0, 0, 1, 1, 1, 0]);
+	///
+	/// let runs = bv.iter_runs().map(|(bit, range)| (bit, range.len()));
+	/// assert_eq!(BitVec::<Msb0, u16>::from_runs(runs), bv);
+	/// ```
+	///
+	/// [`BitSlice::iter_runs`]: crate::slice::BitSlice::iter_runs
+	pub fn from_runs<I>(runs: I) -> Self
+	where I: IntoIterator<Item = (bool, usize)> {
+		let mut out = Self::new();
+		for (bit, count) in runs {
+			out.resize(out.len() + count, bit);
+		}
+		out
+	}
+
 	/// Copies the contents of a [`BitSlice`] into a new allocation.
 	///
 	/// This is an exact copy: the newly-created vector is initialized with a
@@ -3,6 +3,10 @@
 use crate::{
 	boxed::BitBox,
 	order::BitOrder,
+	parse::{
+		self,
+		ParseBitsError,
+	},
 	slice::BitSlice,
 	store::BitStore,
 	vec::BitVec,
@@ -31,6 +35,7 @@ use core::{
 		Hash,
 		Hasher,
 	},
+	str::FromStr,
 };
 
 use tap::tap::Tap;
@@ -254,6 +259,35 @@ where
 	}
 }
 
+impl<O, T> FromStr for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Err = ParseBitsError;
+
+	/// Parses a bit-vector from text, as described in the [`parse`] module.
+	///
+	/// [`parse`]: crate::parse
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		let mut out = Self::new();
+		parse::parse(text, |bit| out.push(bit))?;
+		Ok(out)
+	}
+}
+
+impl<O, T> TryFrom<&str> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Error = ParseBitsError;
+
+	fn try_from(text: &str) -> Result<Self, Self::Error> {
+		text.parse()
+	}
+}
+
 impl<O, T> Default for BitVec<O, T>
 where
 	O: BitOrder,
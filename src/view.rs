@@ -244,7 +244,7 @@ view_bits!(
 	1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
 	22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40,
 	41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59,
-	60, 61, 62, 63, 64
+	60, 61, 62, 63, 64, 128, 256, 512, 1024,
 );
 
 /** Views a region as an immutable [`BitSlice`] only.
@@ -371,7 +371,10 @@ where
 
 #[cfg(test)]
 mod tests {
-	use crate::prelude::*;
+	use crate::{
+		mem::BitMemory,
+		prelude::*,
+	};
 
 	#[test]
 	fn impls() {
@@ -380,4 +383,22 @@ mod tests {
 		assert_eq!(bits.len(), 24);
 		assert!(bits.not_any());
 	}
+
+	#[test]
+	fn wide_arrays() {
+		let mut data = [0u8; 128];
+		let bits = data.view_bits_mut::<Lsb0>();
+		assert_eq!(bits.len(), 1024);
+		bits.set(1023, true);
+		assert_eq!(data[127], 0x80);
+
+		assert_eq!(<[u16; 256] as BitView>::const_bits(), 4096);
+		assert_eq!(<[u32; 512] as BitView>::const_bits(), 16384);
+
+		let mut arr = BitArray::<Msb0, [usize; 1024]>::zeroed();
+		assert_eq!(arr.len(), 1024 * <usize as BitMemory>::BITS as usize);
+		arr.set(0, true);
+		assert_eq!(arr.as_slice()[0], 1 << (<usize as BitMemory>::BITS - 1));
+		assert_eq!(arr.count_ones(), 1);
+	}
 }
@@ -5,7 +5,10 @@ serde-compatible de/ser protocols.
 !*/
 
 #[cfg(all(feature = "alloc", feature = "serde"))]
-use bitvec::prelude::*;
+use bitvec::{
+	prelude::*,
+	serdes::bitseq,
+};
 
 #[test]
 #[cfg(all(feature = "alloc", feature = "serde"))]
@@ -23,6 +26,29 @@ fn serdes_array() {
 	let ba_bare: BitArray<Msb0, u8> =
 		serde_json::from_str(&"178").expect("cannot fail to deserialize");
 	assert_eq!(ba.as_bitslice(), ba_bare.as_bitslice());
+
+	//  Arrays can also use the same format as the dynamic structures.
+	let mut json = Vec::new();
+	bitseq::serialize(&ba, &mut serde_json::Serializer::new(&mut json))
+		.expect("cannot fail to serialize");
+	let bv: BitVec<Lsb0, u16> =
+		serde_json::from_slice(&json).expect("cannot fail to deserialize");
+	assert_eq!(bv, ba);
+
+	let json = serde_json::to_string(&bv).expect("cannot fail to serialize");
+	let mut de = serde_json::Deserializer::from_str(&json);
+	let ba2: BitArray<Lsb0, [u8; 1]> =
+		bitseq::deserialize(&mut de).expect("cannot fail to deserialize");
+	assert_eq!(ba2.as_bitslice(), ba.as_bitslice());
+
+	//  Self-describing formats can also read it without the adapter.
+	let ba3: BitArray<Lsb0, [u8; 1]> =
+		serde_json::from_str(&json).expect("cannot fail to deserialize");
+	assert_eq!(ba3, ba2);
+
+	//  The length must match exactly.
+	let mut de = serde_json::Deserializer::from_str(&json);
+	assert!(bitseq::deserialize::<_, Msb0, [u8; 2]>(&mut de).is_err());
 }
 
 #[test]
@@ -30,11 +56,65 @@ fn serdes_array() {
 fn serdes_vector() {
 	let bv = bitvec![Msb0, u8; 1, 0, 1, 1, 0, 0, 1, 0];
 	let json = serde_json::to_string(&bv).expect("cannot fail to serialize");
-	assert_eq!(json.trim(), r#"{"head":0,"bits":8,"data":[178]}"#);
+	assert_eq!(
+		json.trim(),
+		r#"{"order":"Msb0","width":8,"head":0,"bits":8,"data":[178]}"#
+	);
 
 	let bb: BitBox<Msb0, u8> =
 		serde_json::from_str(&json).expect("cannot fail to deserialize");
 
 	assert!(bb[0]);
 	assert_eq!(bb.as_slice()[0], 178);
+
+	//  Payloads written before the order and width were recorded still load.
+	let old: BitVec<Msb0, u8> =
+		serde_json::from_str(r#"{"head":0,"bits":8,"data":[178]}"#)
+			.expect("cannot fail to deserialize");
+	assert_eq!(old, bv);
+
+	//  Payloads written in another layout are transcoded.
+	let lsb: BitVec<Lsb0, u16> =
+		serde_json::from_str(&json).expect("cannot fail to deserialize");
+	assert_eq!(lsb, bv);
+	assert_eq!(lsb.as_slice()[0], 0b0100_1101);
+}
+
+#[test]
+#[cfg(all(feature = "alloc", feature = "serde"))]
+fn serdes_bincode() {
+	//  `bincode` records neither field names nor the lengths of structs, so it
+	//  exercises the positional form of the format.
+	let bv = bitvec![Msb0, u8; 1, 0, 1, 1, 0, 0, 1, 0, 1];
+	let bytes = bincode::serialize(&bv).expect("cannot fail to serialize");
+
+	let out: BitVec<Msb0, u8> =
+		bincode::deserialize(&bytes).expect("cannot fail to deserialize");
+	assert_eq!(out, bv);
+
+	let out: BitBox<Lsb0, u16> =
+		bincode::deserialize(&bytes).expect("cannot fail to deserialize");
+	assert_eq!(out, bv);
+
+	//  Arrays only read their bare form natively, and need the adapter to read
+	//  the `BitSeq` format.
+	let ba = bitarr![Msb0, u8; 1, 0, 1, 1, 0, 0, 1, 0, 1];
+	let bytes = bincode::serialize(&ba).expect("cannot fail to serialize");
+	let out: BitArray<Msb0, [u8; 2]> =
+		bincode::deserialize(&bytes).expect("cannot fail to deserialize");
+	assert_eq!(out, ba);
+
+	let mut bytes = Vec::new();
+	bitseq::serialize(&ba, &mut bincode::Serializer::new(
+		&mut bytes,
+		bincode::DefaultOptions::new(),
+	))
+	.expect("cannot fail to serialize");
+	let mut de = bincode::Deserializer::from_slice(
+		&bytes,
+		bincode::DefaultOptions::new(),
+	);
+	let out: BitArray<Msb0, [u8; 2]> =
+		bitseq::deserialize(&mut de).expect("cannot fail to deserialize");
+	assert_eq!(out, ba);
 }